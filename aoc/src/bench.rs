@@ -0,0 +1,288 @@
+use crate::alloc;
+use common::cli::{self, Format};
+use common::config::Overrides;
+use common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: aoc bench <day-or-name> [--iterations N] [--format text|json]
+                 [--save FILE] [--baseline FILE] [--threshold PERCENT]
+                 [--config FILE] [--set KEY=VALUE]... [input-file]";
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Wall time and allocations of one phase, summarized over every iteration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseStats {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub max_ms: f64,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+}
+
+/// A day's benchmark, in the same shape as the baseline files it is compared to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bench {
+    pub day: u8,
+    pub name: String,
+    pub iterations: usize,
+    pub phases: BTreeMap<String, PhaseStats>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regressions: Vec<String>,
+}
+
+struct Sample {
+    time: Duration,
+    allocations: usize,
+    allocated_bytes: usize,
+}
+
+fn measure<T>(samples: &mut Vec<Sample>, f: impl FnOnce() -> T) -> T {
+    let (allocations, allocated_bytes) = alloc::snapshot();
+    let start = Instant::now();
+    let v = f();
+    let time = start.elapsed();
+    let (allocations_after, allocated_bytes_after) = alloc::snapshot();
+    samples.push(Sample {
+        time,
+        allocations: allocations_after - allocations,
+        allocated_bytes: allocated_bytes_after - allocated_bytes,
+    });
+    v
+}
+
+fn median<T: Copy + Ord>(mut values: Vec<T>) -> T {
+    values.sort();
+    values[values.len() / 2]
+}
+
+fn summarize(samples: &[Sample]) -> PhaseStats {
+    let millis = |time: Duration| time.as_nanos() as f64 / 1_000_000.0;
+    let times = samples.iter().map(|s| s.time).collect::<Vec<Duration>>();
+    PhaseStats {
+        min_ms: millis(*times.iter().min().unwrap()),
+        median_ms: millis(median(times.clone())),
+        max_ms: millis(*times.iter().max().unwrap()),
+        allocations: median(samples.iter().map(|s| s.allocations).collect()),
+        allocated_bytes: median(samples.iter().map(|s| s.allocated_bytes).collect()),
+    }
+}
+
+/// Parses and solves `input` `iterations` times, measuring each phase.
+///
+/// Unsolved parts are timed like solved ones; only parse errors stop the run.
+pub fn run<S: Solution>(
+    input: &str,
+    iterations: usize,
+    config: &S::Config,
+) -> Result<Bench, ParseError> {
+    let mut samples: [Vec<Sample>; 3] = Default::default();
+    for _ in 0..iterations.max(1) {
+        let [parse, part1, part2] = &mut samples;
+        let parsed = measure(parse, || S::parse(input))?;
+        let _ = measure(part1, || S::part1(&parsed, config));
+        let _ = measure(part2, || S::part2(&parsed, config));
+    }
+    Ok(Bench {
+        day: S::DAY,
+        name: String::from(S::NAME),
+        iterations: iterations.max(1),
+        phases: PHASES
+            .iter()
+            .zip(&samples)
+            .map(|(phase, samples)| (String::from(*phase), summarize(samples)))
+            .collect(),
+        regressions: Vec::new(),
+    })
+}
+
+/// Lists every phase whose median time or allocation count grew by more than
+/// `threshold` percent over `baseline`.
+pub fn compare(bench: &Bench, baseline: &Bench, threshold: f64) -> Vec<String> {
+    let limit = 1.0 + threshold / 100.0;
+    let mut regressions = Vec::new();
+    for (phase, stats) in &bench.phases {
+        let base = match baseline.phases.get(phase) {
+            Some(base) => base,
+            None => continue,
+        };
+        let metrics = [
+            ("median time", stats.median_ms, base.median_ms, "ms"),
+            (
+                "allocations",
+                stats.allocations as f64,
+                base.allocations as f64,
+                "",
+            ),
+        ];
+        for (metric, current, previous, unit) in metrics {
+            if current > previous * limit {
+                let change = if previous > 0.0 {
+                    format!("{:+.1}%", (current / previous - 1.0) * 100.0)
+                } else {
+                    String::from("new")
+                };
+                regressions.push(format!(
+                    "{} {}: {}{} vs {}{} baseline ({})",
+                    phase, metric, current, unit, previous, unit, change
+                ));
+            }
+        }
+    }
+    regressions
+}
+
+fn print_table(bench: &Bench, baseline: Option<&Bench>) {
+    println!("{} ({} iterations)", bench.name, bench.iterations);
+    println!(
+        "{:<6} {:>12} {:>12} {:>12} {:>10} {:>12} {:>10}",
+        "phase", "min ms", "median ms", "max ms", "allocs", "bytes", "baseline"
+    );
+    for (phase, stats) in &bench.phases {
+        let change = baseline
+            .and_then(|baseline| baseline.phases.get(phase))
+            .filter(|base| base.median_ms > 0.0)
+            .map(|base| format!("{:+.1}%", (stats.median_ms / base.median_ms - 1.0) * 100.0))
+            .unwrap_or_default();
+        println!(
+            "{:<6} {:>12.3} {:>12.3} {:>12.3} {:>10} {:>12} {:>10}",
+            phase,
+            stats.min_ms,
+            stats.median_ms,
+            stats.max_ms,
+            stats.allocations,
+            stats.allocated_bytes,
+            change
+        );
+    }
+}
+
+struct Args {
+    day: String,
+    iterations: usize,
+    format: Format,
+    save_path: Option<String>,
+    baseline_path: Option<String>,
+    threshold: f64,
+    overrides: Overrides,
+    input_path: Option<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut positionals = Vec::new();
+    let mut iterations = 10;
+    let mut format = Format::Text;
+    let mut save_path = None;
+    let mut baseline_path = None;
+    let mut threshold = 10.0;
+    let mut overrides = Overrides::new();
+    while let Some(arg) = args.next() {
+        if cli::parse_config_arg(&arg, &mut args, &mut overrides)? {
+            continue;
+        }
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for {}", name));
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                iterations = match value("--iterations")?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(String::from("--iterations must be a positive integer")),
+                };
+            }
+            "--format" | "-f" => {
+                format = match value("--format")?.parse()? {
+                    Format::Csv => return Err(String::from("bench reports text or json")),
+                    format => format,
+                };
+            }
+            "--save" => save_path = Some(value("--save")?),
+            "--baseline" => baseline_path = Some(value("--baseline")?),
+            "--threshold" => {
+                threshold = value("--threshold")?
+                    .parse()
+                    .map_err(|_| String::from("--threshold must be a percentage"))?;
+            }
+            "--help" | "-h" => return Err(String::from(USAGE)),
+            _ => positionals.push(arg),
+        }
+    }
+    let mut positionals = positionals.into_iter();
+    let day = positionals.next().ok_or("missing day")?;
+    let input_path = positionals.next();
+    if let Some(extra) = positionals.next() {
+        return Err(format!("unexpected argument: {}", extra));
+    }
+    Ok(Args {
+        day,
+        iterations,
+        format,
+        save_path,
+        baseline_path,
+        threshold,
+        overrides,
+        input_path,
+    })
+}
+
+fn read_baseline(path: &str) -> Result<Bench, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&contents).map_err(|e| e.to_string())
+}
+
+/// `aoc bench`: exits non-zero when a regression against the baseline is found.
+pub fn main<I: Iterator<Item = String>>(args: I) {
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let day = crate::find_day_or_exit(&args.day);
+    let input = crate::read_input_or_exit(&args.input_path);
+    let baseline = args.baseline_path.as_ref().map(|path| {
+        let baseline = read_baseline(path).unwrap_or_else(|e| {
+            eprintln!("unable to read baseline {}: {}", path, e);
+            process::exit(1);
+        });
+        if baseline.day != day.number {
+            eprintln!(
+                "baseline {} is for day {}, not day {}",
+                path, baseline.day, day.number
+            );
+            process::exit(1);
+        }
+        baseline
+    });
+
+    let mut bench = match (day.bench)(&input, args.iterations, &args.overrides) {
+        Ok(bench) => bench,
+        Err(e) => crate::exit_with(day, e, args.format),
+    };
+    if let Some(path) = &args.save_path {
+        let json = serde_json::to_string_pretty(&bench).unwrap();
+        if let Err(e) = fs::write(path, json + "\n") {
+            eprintln!("unable to save baseline {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+    if let Some(baseline) = &baseline {
+        bench.regressions = compare(&bench, baseline, args.threshold);
+    }
+
+    match args.format {
+        Format::Json => println!("{}", serde_json::to_string(&bench).unwrap()),
+        Format::Text | Format::Csv => {
+            print_table(&bench, baseline.as_ref());
+            for regression in &bench.regressions {
+                eprintln!("regression: {}", regression);
+            }
+        }
+    }
+    if !bench.regressions.is_empty() {
+        process::exit(1);
+    }
+}
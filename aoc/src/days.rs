@@ -0,0 +1,113 @@
+use crate::bench::{self, Bench};
+use common::cli::{self, Report};
+use common::config::Overrides;
+use common::fixtures::{self, Fixture};
+use common::frames::{Frame, Recording, Visualize};
+use common::generate::{self, Generate};
+use common::reference::{self, Mismatch, Reference};
+use common::{ParseError, Progress, Solution};
+
+/// Why a day couldn't run: its overrides don't fit its config, or its input
+/// doesn't parse.
+pub enum Error {
+    Config(String),
+    Parse(ParseError),
+}
+
+/// Records a day's simulation for one part.
+pub type Visualizer = fn(&str, u8, Recording, &Overrides) -> Result<Vec<Frame>, Error>;
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub run: fn(&str, &[u8], &Overrides, &Progress) -> Result<Report, Error>,
+    pub bench: fn(&str, usize, &Overrides) -> Result<Bench, Error>,
+    pub check: fn(&Fixture) -> Vec<String>,
+    pub generate: fn(u64, usize, &Overrides) -> Result<String, String>,
+    pub diff: fn(u64, usize) -> Result<Vec<Mismatch>, ParseError>,
+    /// Only the simulation puzzles can be watched.
+    pub visualize: Option<Visualizer>,
+}
+
+fn run<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    overrides: &Overrides,
+    progress: &Progress,
+) -> Result<Report, Error> {
+    let config = overrides.resolve::<S::Config>().map_err(Error::Config)?;
+    cli::solve_with::<S>(input, parts, &config, progress).map_err(Error::Parse)
+}
+
+fn bench<S: Solution>(
+    input: &str,
+    iterations: usize,
+    overrides: &Overrides,
+) -> Result<Bench, Error> {
+    let config = overrides.resolve::<S::Config>().map_err(Error::Config)?;
+    bench::run::<S>(input, iterations, &config).map_err(Error::Parse)
+}
+
+fn generate<S: Generate>(seed: u64, size: usize, overrides: &Overrides) -> Result<String, String> {
+    let config = overrides.resolve::<S::Config>()?;
+    Ok(generate::generate::<S>(seed, size, &config))
+}
+
+fn visualize<S: Visualize>(
+    input: &str,
+    part: u8,
+    mut recording: Recording,
+    overrides: &Overrides,
+) -> Result<Vec<Frame>, Error> {
+    let config = overrides.resolve::<S::Config>().map_err(Error::Config)?;
+    let input = S::parse(input).map_err(Error::Parse)?;
+    S::record(&input, &config, part, &mut recording);
+    Ok(recording.into_frames())
+}
+
+const fn day<S: Generate + Reference>() -> Day {
+    Day {
+        number: S::DAY,
+        name: S::NAME,
+        run: run::<S>,
+        bench: bench::<S>,
+        check: fixtures::check::<S>,
+        generate: generate::<S>,
+        diff: reference::differential::<S>,
+        visualize: None,
+    }
+}
+
+const fn simulation<S: Generate + Reference + Visualize>() -> Day {
+    Day {
+        visualize: Some(visualize::<S>),
+        ..day::<S>()
+    }
+}
+
+pub const DAYS: [Day; 16] = [
+    day::<calorie_counting::Puzzle>(),
+    day::<rock_paper_scissors::Puzzle>(),
+    day::<rucksack_reorganization::Puzzle>(),
+    day::<camp_cleanup::Puzzle>(),
+    day::<supply_stacks::Puzzle>(),
+    day::<tuning_trouble::Puzzle>(),
+    day::<no_space_left_on_device::Puzzle>(),
+    day::<treetop_tree_house::Puzzle>(),
+    simulation::<rope_bridge::Puzzle>(),
+    simulation::<cathode_ray_tube::Puzzle>(),
+    day::<monkey_in_the_middle::Puzzle>(),
+    day::<hill_climbing_algorithm::Puzzle>(),
+    day::<distress_signal::Puzzle>(),
+    simulation::<regolith_resevoir::Puzzle>(),
+    day::<beacon_exclusion_zone::Puzzle>(),
+    day::<proboscidea_volcanium::Puzzle>(),
+];
+
+/// Looks up a day by its number (`7`, `07`) or crate name (`no-space-left-on-device`).
+pub fn find(query: &str) -> Option<&'static Day> {
+    match query.parse::<u8>() {
+        Ok(number) => DAYS.iter().find(|day| day.number == number),
+        Err(_) => DAYS.iter().find(|day| day.name == query),
+    }
+}
@@ -0,0 +1,73 @@
+use std::process;
+
+const USAGE: &str = "usage: aoc diff <day-or-name> [--seeds N] [--size M]";
+
+struct Args {
+    day: String,
+    seeds: u64,
+    size: usize,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut day = None;
+    let mut seeds = 100;
+    let mut size = 10;
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for {}", name));
+        match arg.as_str() {
+            "--seeds" => {
+                seeds = value("--seeds")?
+                    .parse()
+                    .map_err(|_| String::from("--seeds must be a non-negative integer"))?;
+            }
+            "--size" => {
+                size = value("--size")?
+                    .parse()
+                    .map_err(|_| String::from("--size must be a non-negative integer"))?;
+            }
+            "--help" | "-h" => return Err(String::from(USAGE)),
+            _ if day.is_none() => day = Some(arg),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+    Ok(Args {
+        day: day.ok_or("missing day")?,
+        seeds,
+        size,
+    })
+}
+
+/// `aoc diff`: compares a day's solver with its brute-force reference on the
+/// inputs generated for seeds `0..N`, printing each disagreement with the
+/// seed that replays it.
+pub fn main<I: Iterator<Item = String>>(args: I) {
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let day = crate::find_day_or_exit(&args.day);
+    let mut failed = 0;
+    for seed in 0..args.seeds {
+        match (day.diff)(seed, args.size) {
+            Ok(mismatches) => {
+                for mismatch in &mismatches {
+                    println!("seed {}: {}", seed, mismatch);
+                }
+                if !mismatches.is_empty() {
+                    failed += 1;
+                }
+            }
+            Err(e) => {
+                println!("seed {}: generated input doesn't parse: {}", seed, e);
+                failed += 1;
+            }
+        }
+    }
+    println!("{} seeds, {} disagreed", args.seeds, failed);
+    if failed > 0 {
+        process::exit(1);
+    }
+}
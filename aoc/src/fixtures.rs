@@ -0,0 +1,75 @@
+use crate::days::{self, Day};
+use common::fixtures;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "usage: aoc test [--root DIR] [day-or-name...]";
+
+struct Args {
+    root: PathBuf,
+    days: Vec<&'static Day>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut root = PathBuf::from(".");
+    let mut days = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = PathBuf::from(args.next().ok_or("missing value for --root")?),
+            "--help" | "-h" => return Err(String::from(USAGE)),
+            _ => days.push(crate::find_day_or_exit(&arg)),
+        }
+    }
+    if days.is_empty() {
+        days = days::DAYS.iter().collect();
+    }
+    Ok(Args { root, days })
+}
+
+/// `aoc test`: checks each day against the fixtures in `<root>/<crate>/fixtures`,
+/// exiting non-zero if any answer differs.
+pub fn main<I: Iterator<Item = String>>(args: I) {
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let mut passed = 0;
+    let mut failed = 0;
+    for day in args.days {
+        let fixtures_dir = args.root.join(day.name).join("fixtures");
+        let fixtures = match fixtures::load(&fixtures_dir) {
+            Ok(fixtures) => fixtures,
+            Err(e) => {
+                eprintln!("unable to read {}: {}", fixtures_dir.display(), e);
+                process::exit(1);
+            }
+        };
+        if fixtures.is_empty() {
+            println!(
+                "skip {}: no fixtures in {}",
+                day.name,
+                fixtures_dir.display()
+            );
+        }
+        for fixture in &fixtures {
+            let failures = (day.check)(fixture);
+            if failures.is_empty() {
+                println!("ok   {}/{}", day.name, fixture.name);
+                passed += 1;
+            } else {
+                println!("FAIL {}/{}", day.name, fixture.name);
+                for failure in failures {
+                    println!("       {}", failure);
+                }
+                failed += 1;
+            }
+        }
+    }
+    println!("{} passed, {} failed", passed, failed);
+    if failed > 0 {
+        process::exit(1);
+    }
+}
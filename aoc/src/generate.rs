@@ -0,0 +1,67 @@
+use common::cli;
+use common::config::Overrides;
+use std::process;
+
+const USAGE: &str =
+    "usage: aoc generate <day-or-name> [--seed N] [--size M] [--config FILE] [--set KEY=VALUE]";
+
+struct Args {
+    day: String,
+    seed: u64,
+    size: usize,
+    overrides: Overrides,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = 10;
+    let mut overrides = Overrides::new();
+    while let Some(arg) = args.next() {
+        if cli::parse_config_arg(&arg, &mut args, &mut overrides)? {
+            continue;
+        }
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for {}", name));
+        match arg.as_str() {
+            "--seed" => {
+                seed = value("--seed")?
+                    .parse()
+                    .map_err(|_| String::from("--seed must be a non-negative integer"))?;
+            }
+            "--size" => {
+                size = value("--size")?
+                    .parse()
+                    .map_err(|_| String::from("--size must be a non-negative integer"))?;
+            }
+            "--help" | "-h" => return Err(String::from(USAGE)),
+            _ if day.is_none() => day = Some(arg),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+    Ok(Args {
+        day: day.ok_or("missing day")?,
+        seed,
+        size,
+        overrides,
+    })
+}
+
+/// `aoc generate`: writes a random input for a day to stdout. The same seed,
+/// size and config always give the same input.
+pub fn main<I: Iterator<Item = String>>(args: I) {
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let day = crate::find_day_or_exit(&args.day);
+    match (day.generate)(args.seed, args.size, &args.overrides) {
+        Ok(input) => print!("{}", input),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+}
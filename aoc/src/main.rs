@@ -0,0 +1,248 @@
+use common::batch;
+use common::cli::{self, Format};
+use common::config::Overrides;
+use days::Day;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+use std::time::Duration;
+
+mod alloc;
+mod bench;
+mod days;
+mod diff;
+mod fixtures;
+mod generate;
+mod serve;
+mod visualize;
+
+const USAGE: &str = "usage: aoc <day-or-name> [--part 1|2] [--format text|json|csv]
+           [--config FILE] [--set KEY=VALUE]... [--batch DIR|GLOB]...
+           [--timeout SECS] [--trace FILE] [input-file]
+       aoc bench <day-or-name> [options] [input-file]
+       aoc test [--root DIR] [day-or-name...]
+       aoc generate <day-or-name> [--seed N] [--size M] [--config FILE] [--set KEY=VALUE]
+       aoc diff <day-or-name> [--seeds N] [--size M]
+       aoc visualize <day-or-name> [options] [input-file]
+       aoc serve [--addr HOST:PORT] [--timeout SECS]";
+
+struct Args {
+    day: String,
+    part: Option<u8>,
+    format: Format,
+    overrides: Overrides,
+    batch: Vec<String>,
+    timeout: Option<Duration>,
+    trace: Option<String>,
+    input_path: Option<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut positionals = Vec::new();
+    let mut part = None;
+    let mut format = Format::Text;
+    let mut overrides = Overrides::new();
+    let mut batch = Vec::new();
+    let mut timeout = None;
+    let mut trace = None;
+    while let Some(arg) = args.next() {
+        if cli::parse_config_arg(&arg, &mut args, &mut overrides)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let v = args.next().ok_or("missing value for --part")?;
+                part = match v.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("invalid part: {}", v)),
+                };
+            }
+            "--format" | "-f" => {
+                format = args.next().ok_or("missing value for --format")?.parse()?;
+            }
+            "--batch" | "-b" => {
+                batch.push(args.next().ok_or("missing value for --batch")?);
+            }
+            "--timeout" | "-t" => {
+                let v = args.next().ok_or("missing value for --timeout")?;
+                timeout = Some(cli::parse_timeout(&v)?);
+            }
+            "--trace" => trace = Some(args.next().ok_or("missing value for --trace")?),
+            "--help" | "-h" => return Err(String::from(USAGE)),
+            _ => positionals.push(arg),
+        }
+    }
+    let mut positionals = positionals.into_iter();
+    let day = positionals.next().ok_or("missing day")?;
+    let input_path = positionals.next();
+    if let Some(extra) = positionals.next() {
+        return Err(format!("unexpected argument: {}", extra));
+    }
+    if !batch.is_empty() && input_path.is_some() {
+        return Err(String::from("--batch replaces the input file"));
+    }
+    Ok(Args {
+        day,
+        part,
+        format,
+        overrides,
+        batch,
+        timeout,
+        trace,
+        input_path,
+    })
+}
+
+fn read_input(input_path: &Option<String>) -> io::Result<String> {
+    match input_path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+fn find_day_or_exit(query: &str) -> &'static Day {
+    match days::find(query) {
+        Some(day) => day,
+        None => {
+            let names = days::DAYS
+                .iter()
+                .map(|day| format!("  {:>2} {}", day.number, day.name))
+                .collect::<Vec<String>>();
+            eprintln!(
+                "unknown day: {}\navailable days:\n{}",
+                query,
+                names.join("\n")
+            );
+            process::exit(2);
+        }
+    }
+}
+
+fn read_input_or_exit(input_path: &Option<String>) -> String {
+    match read_input(input_path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("unable to read input: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Reports why `day` couldn't run and exits: bad overrides are usage errors.
+fn exit_with(day: &Day, error: days::Error, format: Format) -> ! {
+    match error {
+        days::Error::Config(e) => {
+            eprintln!("{}", e);
+            cli::exit(2);
+        }
+        days::Error::Parse(e) => {
+            cli::print_parse_error(day.number, day.name, &e, format);
+            cli::exit(1);
+        }
+    }
+}
+
+/// Solves every file `--batch` names, one row each, carrying on past failures.
+/// `--timeout` applies to each file separately.
+fn run_batch(day: &Day, args: &Args, parts: &[u8]) {
+    let paths = cli::expand_batch(&args.batch).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        cli::exit(2);
+    });
+    let rows = batch::run(&paths, |input| {
+        let progress = cli::progress(args.timeout);
+        (day.run)(input, parts, &args.overrides, &progress).map_err(|e| match e {
+            // Bad overrides fail every file alike, so stop at the first.
+            days::Error::Config(e) => {
+                eprintln!("{}", e);
+                cli::exit(2);
+            }
+            days::Error::Parse(e) => e.to_string(),
+        })
+    });
+    cli::finish_batch(&rows, args.format);
+}
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("bench") => {
+            args.next();
+            bench::main(args);
+            return;
+        }
+        Some("test") => {
+            args.next();
+            fixtures::main(args);
+            return;
+        }
+        Some("diff") => {
+            args.next();
+            diff::main(args);
+            return;
+        }
+        Some("generate") => {
+            args.next();
+            generate::main(args);
+            return;
+        }
+        Some("serve") => {
+            args.next();
+            serve::main(args);
+            return;
+        }
+        Some("visualize") => {
+            args.next();
+            visualize::main(args);
+            return;
+        }
+        _ => {}
+    }
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let day = find_day_or_exit(&args.day);
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    cli::start_trace(&args.trace);
+    if !args.batch.is_empty() {
+        run_batch(day, &args, &parts);
+        cli::exit(0);
+    }
+    let input = read_input_or_exit(&args.input_path);
+
+    let progress = cli::with_progress_bar(cli::progress(args.timeout));
+    let solved = (day.run)(&input, &parts, &args.overrides, &progress);
+    cli::clear_progress_bar();
+    let report = match solved {
+        Ok(report) => report,
+        Err(e) => exit_with(day, e, args.format),
+    };
+    let labels = parts
+        .iter()
+        .map(|part| format!("Part {}", part))
+        .collect::<Vec<String>>();
+    report.print(
+        args.format,
+        &labels.iter().map(String::as_str).collect::<Vec<&str>>(),
+    );
+    if let (true, Some(timeout)) = (report.is_cancelled(), args.timeout) {
+        eprintln!("timed out after {:?}", timeout);
+    }
+    if !report.is_solved() {
+        cli::exit(1);
+    }
+    cli::exit(0);
+}
@@ -0,0 +1,256 @@
+use crate::days::{self, Day};
+use common::cli;
+use common::config::Overrides;
+use common::{Answer, ParseError, SolveError};
+use serde::Serialize;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const USAGE: &str = "usage: aoc serve [--addr HOST:PORT] [--timeout SECS]";
+
+/// How long each request may solve for unless `--timeout` says otherwise.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A solved part.
+#[derive(Serialize)]
+struct Solved<'a> {
+    day: u8,
+    name: &'a str,
+    part: u8,
+    answer: &'a Answer,
+    time_ms: f64,
+}
+
+/// Parse errors keep the shape `--format json` prints, with the location in
+/// an object; anything else is a message.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Reason<'a> {
+    Message(String),
+    Parse(&'a ParseError),
+}
+
+#[derive(Serialize)]
+struct Failed<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    error: Reason<'a>,
+}
+
+impl Failed<'_> {
+    fn new(error: impl Into<String>) -> Self {
+        Failed {
+            day: None,
+            name: None,
+            error: Reason::Message(error.into()),
+        }
+    }
+}
+
+fn json(status: u16, body: &impl Serialize) -> (u16, String) {
+    (status, serde_json::to_string(body).unwrap())
+}
+
+/// Decodes `%XX` escapes and `+` in a query string component.
+fn percent_decode(s: &str) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    let mut rest = s.bytes();
+    while let Some(b) = rest.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [rest.next(), rest.next()];
+                let digit = |d: Option<u8>| (d? as char).to_digit(16);
+                match (digit(hex[0]), digit(hex[1])) {
+                    (Some(hi), Some(lo)) => bytes.push((hi * 16 + lo) as u8),
+                    _ => return Err(format!("invalid escape in query: {}", s)),
+                }
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| format!("query is not UTF-8: {}", s))
+}
+
+/// Each `key=value` in the query overrides one puzzle constant, as `--set`
+/// does on the command line.
+fn parse_query(query: &str) -> Result<Overrides, String> {
+    let mut overrides = Overrides::new();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        overrides.set(&percent_decode(pair)?)?;
+    }
+    Ok(overrides)
+}
+
+/// What a panic was raised with, when it's a message.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("no message", String::as_str),
+    }
+}
+
+/// Solves one part, cancelled after `timeout`. A panicking solver fails only
+/// its own request, as a 500.
+fn solve(
+    day: &Day,
+    part: u8,
+    input: &str,
+    overrides: &Overrides,
+    timeout: Duration,
+) -> (u16, String) {
+    let progress = cli::progress(Some(timeout));
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        (day.run)(input, &[part], overrides, &progress)
+    }));
+    let report = match run {
+        Ok(Ok(report)) => report,
+        Ok(Err(days::Error::Config(e))) => return json(400, &Failed::new(e)),
+        Ok(Err(days::Error::Parse(e))) => {
+            return json(
+                400,
+                &Failed {
+                    day: Some(day.number),
+                    name: Some(day.name),
+                    error: Reason::Parse(&e),
+                },
+            )
+        }
+        Err(payload) => {
+            return json(
+                500,
+                &Failed {
+                    day: Some(day.number),
+                    name: Some(day.name),
+                    error: Reason::Message(format!(
+                        "solver panicked: {}",
+                        panic_message(payload.as_ref())
+                    )),
+                },
+            )
+        }
+    };
+    let solved = &report.parts[0];
+    match &solved.answer {
+        Ok(answer) => json(
+            200,
+            &Solved {
+                day: day.number,
+                name: day.name,
+                part,
+                answer,
+                time_ms: report.time().as_micros() as f64 / 1000.0,
+            },
+        ),
+        Err(e) => json(
+            match e {
+                SolveError::NoSolution(_) => 422,
+                SolveError::Cancelled(_) => 503,
+            },
+            &Failed {
+                day: Some(day.number),
+                name: Some(day.name),
+                error: Reason::Message(e.to_string()),
+            },
+        ),
+    }
+}
+
+/// Routes one request to its status and JSON body. Only
+/// `POST /day/{name}/part/{n}` exists; the body is the raw puzzle input.
+fn route(
+    method: &Method,
+    url: &str,
+    body: Result<String, String>,
+    timeout: Duration,
+) -> (u16, String) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+    let (name, part) = match segments[..] {
+        ["day", name, "part", part] => (name, part),
+        _ => return json(404, &Failed::new(format!("no route for {}", path))),
+    };
+    if *method != Method::Post {
+        return json(
+            405,
+            &Failed::new("use POST with the puzzle input as the body"),
+        );
+    }
+    let day = match days::find(name) {
+        Some(day) => day,
+        None => return json(404, &Failed::new(format!("unknown day: {}", name))),
+    };
+    let part = match part {
+        "1" => 1,
+        "2" => 2,
+        _ => return json(404, &Failed::new(format!("invalid part: {}", part))),
+    };
+    let overrides = match parse_query(query) {
+        Ok(overrides) => overrides,
+        Err(e) => return json(400, &Failed::new(e)),
+    };
+    match body {
+        Ok(input) => solve(day, part, &input, &overrides, timeout),
+        Err(e) => json(400, &Failed::new(e)),
+    }
+}
+
+fn handle(mut request: Request, timeout: Duration) {
+    let mut body = String::new();
+    let body = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => Ok(body),
+        Err(e) => Err(format!("unable to read body: {}", e)),
+    };
+    let (status, json) = route(request.method(), request.url(), body, timeout);
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json)
+        .with_status_code(status)
+        .with_header(content_type);
+    // The client may have hung up; there's no one left to tell.
+    let _ = request.respond(response);
+}
+
+/// `aoc serve`: answers `POST /day/{name}/part/{n}` with JSON, one thread per
+/// request. Query parameters override puzzle constants, e.g.
+/// `/day/15/part/1?row=10`. Each request is cancelled after `--timeout`
+/// seconds.
+pub fn main<I: Iterator<Item = String>>(mut args: I) {
+    let mut addr = String::from("127.0.0.1:8022");
+    let mut timeout = DEFAULT_TIMEOUT;
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--addr", Some(value)) => addr = value,
+            ("--timeout", Some(value)) => match cli::parse_timeout(&value) {
+                Ok(value) => timeout = value,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(2);
+                }
+            },
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+    let server = match Server::http(&addr) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("unable to listen on {}: {}", addr, e);
+            process::exit(1);
+        }
+    };
+    // Port 0 picks a free port, so report the one actually bound.
+    println!("listening on http://{}", server.server_addr());
+    for request in server.incoming_requests() {
+        thread::spawn(move || handle(request, timeout));
+    }
+}
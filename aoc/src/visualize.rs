@@ -0,0 +1,167 @@
+use crate::days::{self, Day};
+use common::cli::{self, Format};
+use common::config::Overrides;
+use common::frames::Recording;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process;
+use visualize::Options;
+
+const USAGE: &str = "usage: aoc visualize <day-or-name> [--part 1|2] [--format text|play|gif|apng]
+           [--output FILE] [--frames N] [--every N] [--fps N] [--scale N]
+           [--config FILE] [--set KEY=VALUE]... [input-file]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exporter {
+    Text,
+    Play,
+    Gif,
+    Apng,
+}
+
+struct Args {
+    day: String,
+    part: u8,
+    exporter: Exporter,
+    output: Option<String>,
+    frames: usize,
+    every: usize,
+    options: Options,
+    overrides: Overrides,
+    input_path: Option<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut positionals = Vec::new();
+    let mut part = 1;
+    let mut exporter = Exporter::Text;
+    let mut output = None;
+    let mut frames = 300;
+    let mut every = 1;
+    let mut options = Options::default();
+    let mut overrides = Overrides::new();
+    while let Some(arg) = args.next() {
+        if cli::parse_config_arg(&arg, &mut args, &mut overrides)? {
+            continue;
+        }
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for {}", name));
+        let positive = |name: &str, v: String| match v.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("{} must be a positive integer", name)),
+        };
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let v = value("--part")?;
+                part = match v.as_str() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => return Err(format!("invalid part: {}", v)),
+                };
+            }
+            "--format" | "-f" => {
+                let v = value("--format")?;
+                exporter = match v.as_str() {
+                    "text" => Exporter::Text,
+                    "play" => Exporter::Play,
+                    "gif" => Exporter::Gif,
+                    "apng" => Exporter::Apng,
+                    _ => return Err(format!("invalid format: {}", v)),
+                };
+            }
+            "--output" | "-o" => output = Some(value("--output")?),
+            "--frames" => frames = positive("--frames", value("--frames")?)?,
+            "--every" => every = positive("--every", value("--every")?)?,
+            "--fps" => {
+                options.fps = positive("--fps", value("--fps")?)?
+                    .try_into()
+                    .map_err(|_| String::from("--fps is too large"))?;
+            }
+            "--scale" => options.scale = positive("--scale", value("--scale")?)?,
+            "--help" | "-h" => return Err(String::from(USAGE)),
+            _ => positionals.push(arg),
+        }
+    }
+    let mut positionals = positionals.into_iter();
+    let day = positionals.next().ok_or("missing day")?;
+    let input_path = positionals.next();
+    if let Some(extra) = positionals.next() {
+        return Err(format!("unexpected argument: {}", extra));
+    }
+    if exporter == Exporter::Play && output.is_some() {
+        return Err(String::from(
+            "--format play draws to the terminal; drop --output",
+        ));
+    }
+    Ok(Args {
+        day,
+        part,
+        exporter,
+        output,
+        frames,
+        every,
+        options,
+        overrides,
+        input_path,
+    })
+}
+
+fn supported_days() -> String {
+    days::DAYS
+        .iter()
+        .filter(|day| day.visualize.is_some())
+        .map(|day| format!("  {:>2} {}", day.number, day.name))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `aoc visualize`: records a simulation puzzle step by step and exports the
+/// frames as text, terminal playback or an animated image.
+pub fn main<I: Iterator<Item = String>>(args: I) {
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let day: &Day = crate::find_day_or_exit(&args.day);
+    let record = match day.visualize {
+        Some(record) => record,
+        None => {
+            eprintln!(
+                "{} has no simulation to visualize; try one of:\n{}",
+                day.name,
+                supported_days()
+            );
+            process::exit(2);
+        }
+    };
+    let input = crate::read_input_or_exit(&args.input_path);
+
+    let recording = Recording::new(args.frames).every(args.every);
+    let frames = match record(&input, args.part, recording, &args.overrides) {
+        Ok(frames) => frames,
+        Err(e) => crate::exit_with(day, e, Format::Text),
+    };
+
+    let out: Box<dyn Write> = match &args.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(e) => {
+                eprintln!("unable to create {}: {}", path, e);
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+    let result = match args.exporter {
+        Exporter::Text => visualize::write_text(&frames, out),
+        Exporter::Play => visualize::play(&frames, &args.options, out),
+        Exporter::Gif => visualize::write_gif(&frames, &args.options, out),
+        Exporter::Apng => visualize::write_apng(&frames, &args.options, out),
+    };
+    if let Err(e) = result {
+        eprintln!("unable to write frames: {}", e);
+        process::exit(1);
+    }
+}
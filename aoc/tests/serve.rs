@@ -0,0 +1,132 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+/// `aoc serve` on a free port, stopped when dropped.
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start() -> Self {
+        Server::start_with(&[])
+    }
+
+    fn start_with(args: &[&str]) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--addr", "127.0.0.1:0"])
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let addr = line.trim().trim_start_matches("listening on http://");
+        Server {
+            addr: addr.to_string(),
+            child,
+        }
+    }
+
+    /// Sends one request and returns the status code and body.
+    fn request(&self, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        (status, body.to_string())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+const CALORIES: &str = include_str!("../../calorie-counting/fixtures/example/input.txt");
+const BEACONS: &str = include_str!("../../beacon-exclusion-zone/fixtures/example/input.txt");
+
+#[test]
+fn solves_by_number_or_name() {
+    let server = Server::start();
+    let (status, body) = server.request("POST", "/day/1/part/1", CALORIES);
+    assert_eq!(status, 200);
+    assert!(body.starts_with(r#"{"day":1,"name":"calorie-counting","part":1,"answer":24000,"#));
+    let (status, body) = server.request("POST", "/day/calorie-counting/part/2", CALORIES);
+    assert_eq!(status, 200);
+    assert!(body.contains(r#""answer":45000"#));
+}
+
+#[test]
+fn query_overrides_constants() {
+    let server = Server::start();
+    let (status, body) = server.request("POST", "/day/15/part/2?row=10&max_coordinate=20", BEACONS);
+    assert_eq!(status, 200);
+    assert!(body.contains(r#""answer":56000011"#));
+}
+
+#[test]
+fn reports_parse_errors_as_bad_requests() {
+    let server = Server::start();
+    let (status, body) = server.request("POST", "/day/1/part/1", "1000\nabc\n");
+    assert_eq!(status, 400);
+    assert_eq!(
+        body,
+        r#"{"day":1,"name":"calorie-counting","error":{"line":2,"column":1,"text":"abc","message":"expected a calorie count"}}"#
+    );
+}
+
+#[test]
+fn rejects_bad_routes() {
+    let server = Server::start();
+    assert_eq!(server.request("POST", "/day/99/part/1", "").0, 404);
+    assert_eq!(server.request("POST", "/day/1/part/3", "").0, 404);
+    assert_eq!(server.request("POST", "/days", "").0, 404);
+    assert_eq!(server.request("GET", "/day/1/part/1", "").0, 405);
+    assert_eq!(
+        server.request("POST", "/day/1/part/1?row=1", CALORIES).0,
+        400
+    );
+}
+
+#[test]
+fn times_out_long_solves() {
+    let server = Server::start_with(&["--timeout", "0.2"]);
+    // One sensor covers every row, so part 2 scans all of them.
+    let input = "Sensor at x=0, y=0: closest beacon is at x=0, y=1000000000\n";
+    let (status, body) = server.request("POST", "/day/15/part/2?max_coordinate=100000000", input);
+    assert_eq!(status, 503);
+    assert!(body.starts_with(r#"{"day":15,"name":"beacon-exclusion-zone","error":"cancelled: "#));
+    assert_eq!(server.request("POST", "/day/1/part/1", CALORIES).0, 200);
+}
+
+// Relies on overflow checks to make the solver panic.
+#[cfg(debug_assertions)]
+#[test]
+fn survives_a_panicking_solver() {
+    let server = Server::start();
+    // Counting the rows to scan overflows.
+    let path = format!("/day/15/part/2?max_coordinate={}", i64::MAX);
+    let (status, body) = server.request("POST", &path, BEACONS);
+    assert_eq!(status, 500);
+    assert!(
+        body.starts_with(r#"{"day":15,"name":"beacon-exclusion-zone","error":"solver panicked: "#)
+    );
+    assert_eq!(server.request("POST", "/day/1/part/1", CALORIES).0, 200);
+}
@@ -0,0 +1,51 @@
+use std::fs;
+use std::process::Command;
+
+/// Names of the spans a Chrome trace opens, in order.
+fn spans(path: &std::path::Path) -> Vec<String> {
+    let trace: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    trace
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|event| event["ph"] == "B")
+        .map(|event| event["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn writes_chrome_trace() {
+    let path = std::env::temp_dir().join(format!("aoc-trace-{}.json", std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["14", "--trace"])
+        .arg(&path)
+        .arg("../regolith-resevoir/fixtures/example/input.txt")
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success());
+    let spans = spans(&path);
+    assert_eq!(spans[..3], ["solve", "parse", "part1"]);
+    assert!(spans.contains(&String::from("part2")));
+    assert_eq!(
+        spans.iter().filter(|span| *span == "drop_sand").count(),
+        24 + 1 + 93 + 1
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn finishes_trace_on_failure() {
+    let path = std::env::temp_dir().join(format!("aoc-trace-failed-{}.json", std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["1", "--trace"])
+        .arg(&path)
+        .arg("../rope-bridge/fixtures/example/input.txt")
+        .output()
+        .unwrap()
+        .status;
+    assert_eq!(status.code(), Some(1));
+    assert_eq!(spans(&path), ["solve", "parse"]);
+    fs::remove_file(&path).unwrap();
+}
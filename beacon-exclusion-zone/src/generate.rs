@@ -0,0 +1,63 @@
+//! `size` random sensors across the configured search area.
+//!
+//! Every sensor's beacon is closer than a hidden point, so the search area
+//! always has at least one uncovered position, which may sit on the edge of
+//! the area. Small areas get more sensors until the hidden point is the only
+//! gap, as in the real puzzle.
+
+use crate::{find_manhattan_dist, Config, Puzzle};
+use common::generate::{Generate, Rng};
+
+/// Areas up to this many positions are filled in until one gap is left.
+const FILL_LIMIT: i64 = 1 << 16;
+
+fn sensor_line(sensor: (i64, i64), beacon: (i64, i64)) -> String {
+    format!(
+        "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+        sensor.0, sensor.1, beacon.0, beacon.1
+    )
+}
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, config: &Config) -> String {
+        let max = config.max_coordinate.max(0);
+        let hidden = (rng.range(0..=max), rng.range(0..=max));
+        let mut sensors = Vec::new();
+        while sensors.len() < size.max(1) {
+            let sensor = (rng.range(0..=max), rng.range(0..=max));
+            let reach = find_manhattan_dist(sensor, hidden) - 1;
+            if reach < 1 {
+                continue;
+            }
+            let dist = rng.range(1..=reach.min((max / 3).max(1)));
+            let dx = rng.range(-dist..=dist);
+            let dy = *rng.pick(&[-1, 1]) * (dist - dx.abs());
+            sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+        }
+
+        if (max + 1) * (max + 1) <= FILL_LIMIT {
+            // Cover each remaining gap with a sensor one step further from
+            // the hidden point, reaching as far as it can without covering it.
+            for y in 0..=max {
+                for x in 0..=max {
+                    let covered = sensors.iter().any(|&(sensor, beacon)| {
+                        find_manhattan_dist(sensor, (x, y)) <= find_manhattan_dist(sensor, beacon)
+                    });
+                    if covered || (x, y) == hidden {
+                        continue;
+                    }
+                    let sensor = (x + (x - hidden.0).signum(), y + (y - hidden.1).signum());
+                    let reach = find_manhattan_dist(sensor, hidden) - 1;
+                    let dx = rng.range(-reach..=reach);
+                    let dy = *rng.pick(&[-1, 1]) * (reach - dx.abs());
+                    sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+                }
+            }
+        }
+
+        sensors
+            .into_iter()
+            .map(|(sensor, beacon)| sensor_line(sensor, beacon))
+            .collect()
+    }
+}
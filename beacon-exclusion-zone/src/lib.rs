@@ -0,0 +1,217 @@
+use common::{parse_at, Answer, ParseError, Progress, Solution, SolveError};
+use interval_set::IntervalSet;
+use lazy_static::lazy_static;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+mod generate;
+mod reference;
+
+lazy_static! {
+    static ref SENSOR_RE: Regex =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .unwrap();
+}
+
+/// Puzzle constants, overridable through `common::config`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The row part 1 counts.
+    pub row: i64,
+    /// Part 2 searches `0..=max_coordinate` on both axes.
+    pub max_coordinate: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            row: 2000000,
+            max_coordinate: 4000000,
+        }
+    }
+}
+
+/// Largest coordinate, either way, that a sensor or beacon may have. Distances
+/// reach four times this and row ranges five, which all fit in an `i64`.
+pub const COORDINATE_LIMIT: i64 = 1 << 59;
+
+#[derive(Debug)]
+pub struct Sensor {
+    location: (i64, i64),
+    closest_beacon_location: (i64, i64),
+}
+
+impl Sensor {
+    pub fn parse(input: &str, s: &str) -> Result<Sensor, ParseError> {
+        let cap = SENSOR_RE.captures(s).ok_or_else(|| {
+            ParseError::at(
+                input,
+                s,
+                "expected \"Sensor at x=.., y=..: closest beacon is at x=.., y=..\"",
+            )
+        })?;
+        let coord = |i| {
+            let token = cap.get(i).unwrap().as_str();
+            let coord = parse_at::<i64>(input, token, "a coordinate")?;
+            if coord.unsigned_abs() > COORDINATE_LIMIT as u64 {
+                return Err(ParseError::at(input, token, "coordinate out of range"));
+            }
+            Ok(coord)
+        };
+        let location_x = coord(1)?;
+        let location_y = coord(2)?;
+        let closest_beacon_location_x = coord(3)?;
+        let closest_beacon_location_y = coord(4)?;
+        Ok(Sensor {
+            location: (location_x, location_y),
+            closest_beacon_location: (closest_beacon_location_x, closest_beacon_location_y),
+        })
+    }
+
+    pub fn find_x_range(&self, y: i64) -> (i64, i64) {
+        let (location_x, location_y) = self.location;
+        let closest_beacon_dist = find_manhattan_dist(self.location, self.closest_beacon_location);
+        // Rows beyond reach come out empty, however far away they are.
+        let y_dist = location_y.abs_diff(y).min(closest_beacon_dist as u64 + 1) as i64;
+        let remaining_dist = closest_beacon_dist - y_dist;
+        let min_x = location_x - remaining_dist;
+        let max_x = location_x + remaining_dist;
+        (min_x, max_x)
+    }
+}
+
+pub fn find_manhattan_dist(a: (i64, i64), b: (i64, i64)) -> i64 {
+    let (a_x, a_y) = a;
+    let (b_x, b_y) = b;
+    (a_x - b_x).abs() + (a_y - b_y).abs()
+}
+
+pub fn find_non_overlapping_x_ranges(sensors: &[Sensor], y: i64) -> IntervalSet {
+    sensors.iter().map(|v| v.find_x_range(y)).collect()
+}
+
+pub fn find_total_non_beacon_locations(sensors: &[Sensor], y: i64) -> i64 {
+    let beacon_locations = sensors
+        .iter()
+        .map(|v| v.closest_beacon_location)
+        .collect::<HashSet<(i64, i64)>>();
+
+    let x_ranges = find_non_overlapping_x_ranges(sensors, y);
+
+    let beacons_in_x_ranges = beacon_locations
+        .iter()
+        .filter(|&&(beacon_x, beacon_y)| beacon_y == y && x_ranges.contains(beacon_x))
+        .count();
+
+    x_ranges.covered_len() as i64 - beacons_in_x_ranges as i64
+}
+
+/// Scans rows of the `bounds` square for the one position no sensor covers.
+/// Rows are scanned in parallel with the `parallel` feature; the lowest row
+/// with a gap still wins.
+pub fn find_distress_beacon_location(sensors: &[Sensor], bounds: (i64, i64)) -> Option<(i64, i64)> {
+    find_distress_beacon_location_with(sensors, bounds, &Progress::new())
+        .expect("a bare Progress never cancels")
+}
+
+/// Rows between progress reports and deadline checks.
+const REPORT_EVERY: u64 = 4096;
+
+/// Like [`find_distress_beacon_location`], reporting rows scanned to
+/// `progress`. When cancelled, says how many rows were cleared.
+pub fn find_distress_beacon_location_with(
+    sensors: &[Sensor],
+    bounds: (i64, i64),
+    progress: &Progress,
+) -> Result<Option<(i64, i64)>, SolveError> {
+    let (min, max) = bounds;
+    let total = (max - min + 1).max(0) as u64;
+    let scanned = AtomicU64::new(0);
+    let find_gap = |y: i64| {
+        let done = scanned.fetch_add(1, Ordering::Relaxed) + 1;
+        if done.is_multiple_of(REPORT_EVERY) {
+            progress.report(done, total);
+            if progress.is_cancelled() {
+                // Stops the scan; rows above this one all stop too.
+                return Some(None);
+            }
+        }
+        // A gap at either end of the row counts as much as one between
+        // sensors, as does a row no sensor reaches.
+        find_non_overlapping_x_ranges(sensors, y)
+            .gaps((min, max))
+            .next()
+            .map(|(x, _)| Some((x, y)))
+    };
+    #[cfg(feature = "parallel")]
+    let found = (min..=max).into_par_iter().find_map_first(find_gap);
+    #[cfg(not(feature = "parallel"))]
+    let found = (min..=max).find_map(find_gap);
+    match found {
+        Some(None) => Err(SolveError::Cancelled(format!(
+            "scanned {} of {} rows without finding the beacon",
+            scanned.load(Ordering::Relaxed),
+            total
+        ))),
+        Some(location) => Ok(location),
+        None => {
+            progress.report(total, total);
+            Ok(None)
+        }
+    }
+}
+
+pub fn calculate_tuning_frequency(location: (i64, i64)) -> i64 {
+    let (x, y) = location;
+    4000000 * x + y
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    input.lines().map(|l| Sensor::parse(input, l)).collect()
+}
+
+pub fn part1(sensors: &[Sensor], config: &Config) -> i64 {
+    find_total_non_beacon_locations(sensors, config.row)
+}
+
+pub fn part2(sensors: &[Sensor], config: &Config) -> Option<i64> {
+    find_distress_beacon_location(sensors, (0, config.max_coordinate))
+        .map(calculate_tuning_frequency)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 15;
+    const NAME: &'static str = "beacon-exclusion-zone";
+
+    type Input = Vec<Sensor>;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(sensors: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(sensors, config).into())
+    }
+
+    fn part2(sensors: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Self::part2_with(sensors, config, &Progress::new())
+    }
+
+    fn part2_with(
+        sensors: &Self::Input,
+        config: &Self::Config,
+        progress: &Progress,
+    ) -> Result<Answer, SolveError> {
+        find_distress_beacon_location_with(sensors, (0, config.max_coordinate), progress)?
+            .map(|location| calculate_tuning_frequency(location).into())
+            .ok_or_else(|| SolveError::NoSolution(String::from("no uncovered location in range")))
+    }
+}
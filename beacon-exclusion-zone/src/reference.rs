@@ -0,0 +1,60 @@
+//! Asks every sensor about every position, one position at a time.
+
+use crate::{calculate_tuning_frequency, find_manhattan_dist, Config, Puzzle, Sensor};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+fn is_covered(sensors: &[Sensor], position: (i64, i64)) -> bool {
+    sensors.iter().any(|sensor| {
+        find_manhattan_dist(sensor.location, position)
+            <= find_manhattan_dist(sensor.location, sensor.closest_beacon_location)
+    })
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(sensors: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let reach =
+            |sensor: &Sensor| find_manhattan_dist(sensor.location, sensor.closest_beacon_location);
+        let min_x = sensors
+            .iter()
+            .map(|s| s.location.0 - reach(s))
+            .min()
+            .unwrap_or(0);
+        let max_x = sensors
+            .iter()
+            .map(|s| s.location.0 + reach(s))
+            .max()
+            .unwrap_or(0);
+        let mut count = 0i64;
+        for x in min_x..=max_x {
+            let position = (x, config.row);
+            let is_beacon = sensors
+                .iter()
+                .any(|s| s.closest_beacon_location == position);
+            if is_covered(sensors, position) && !is_beacon {
+                count += 1;
+            }
+        }
+        Ok(count.into())
+    }
+
+    fn reference_part2(sensors: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        for y in 0..=config.max_coordinate {
+            for x in 0..=config.max_coordinate {
+                if !is_covered(sensors, (x, y)) {
+                    return Ok(calculate_tuning_frequency((x, y)).into());
+                }
+            }
+        }
+        Err(SolveError::NoSolution(String::from(
+            "every position is covered",
+        )))
+    }
+
+    fn reference_config() -> Self::Config {
+        Config {
+            row: 10,
+            max_coordinate: 20,
+        }
+    }
+}
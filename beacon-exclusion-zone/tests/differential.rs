@@ -0,0 +1,40 @@
+use beacon_exclusion_zone::{self as day, Puzzle};
+use common::reference::{compare, differential, Reference};
+use common::Solution;
+use proptest::prelude::*;
+
+/// Gaps on the edge of the search square, outside every sensor's range on
+/// that row.
+#[test]
+fn finds_gaps_on_the_edge() {
+    let cases = [
+        // Covers the middle of the square but none of its corners.
+        (
+            "Sensor at x=10, y=10: closest beacon is at x=10, y=0\n",
+            (0, 0),
+        ),
+        // Covers all of row 0 but its last column.
+        (
+            "Sensor at x=-100, y=10: closest beacon is at x=29, y=10\n",
+            (20, 0),
+        ),
+    ];
+    let config = Puzzle::reference_config();
+    for (input, location) in cases {
+        let sensors = Puzzle::parse(input).unwrap();
+        assert_eq!(
+            day::find_distress_beacon_location(&sensors, (0, config.max_coordinate)),
+            Some(location)
+        );
+        assert_eq!(compare::<Puzzle>(&sensors, &config), vec![]);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn solver_matches_reference(seed in any::<u64>(), size in 1usize..12) {
+        prop_assert_eq!(differential::<Puzzle>(seed, size).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,32 @@
+use beacon_exclusion_zone::{Config, Puzzle, COORDINATE_LIMIT};
+use common::reference::Reference;
+use common::Solution;
+
+#[test]
+fn rejects_coordinates_out_of_range() {
+    let input = format!(
+        "Sensor at x=0, y=0: closest beacon is at x={}, y=0\n",
+        i64::MAX
+    );
+    let e = Puzzle::parse(&input).unwrap_err();
+    assert_eq!(e.message, "coordinate out of range");
+    assert_eq!(e.text, i64::MAX.to_string());
+}
+
+#[test]
+fn solves_at_the_coordinate_limit() {
+    let (min, max) = (-COORDINATE_LIMIT, COORDINATE_LIMIT);
+    let input = format!(
+        "Sensor at x={min}, y={min}: closest beacon is at x={max}, y={max}\n\
+         Sensor at x={max}, y={max}: closest beacon is at x={min}, y={min}\n",
+    );
+    let sensors = Puzzle::parse(&input).unwrap();
+    for row in [0, i64::MIN, i64::MAX] {
+        let config = Config {
+            row,
+            ..Puzzle::reference_config()
+        };
+        assert!(Puzzle::part1(&sensors, &config).is_ok());
+        assert!(Puzzle::part2(&sensors, &config).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+use beacon_exclusion_zone::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,19 @@
+use beacon_exclusion_zone::{self as day, Puzzle};
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..12) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let max = day::Config::default().max_coordinate;
+        let (x, y) = day::find_distress_beacon_location(&input, (0, max)).unwrap();
+        for sensor in &input {
+            let (start, end) = sensor.find_x_range(y);
+            prop_assert!(x < start || x > end);
+        }
+    }
+}
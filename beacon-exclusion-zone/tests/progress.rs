@@ -0,0 +1,53 @@
+use beacon_exclusion_zone::{self as day, Config, Puzzle};
+use common::{Progress, Solution, SolveError};
+
+/// Four corner sensors that reach everywhere but the middle of the search
+/// square, leaving a gap on row 10000.
+const FAR: &str = "Sensor at x=0, y=0: closest beacon is at x=19999, y=0
+Sensor at x=20000, y=0: closest beacon is at x=1, y=0
+Sensor at x=0, y=20000: closest beacon is at x=19999, y=20000
+Sensor at x=20000, y=20000: closest beacon is at x=1, y=20000
+";
+
+#[test]
+fn finds_the_gap_with_progress() {
+    let sensors = Puzzle::parse(FAR).unwrap();
+    let location = day::find_distress_beacon_location_with(&sensors, (0, 20000), &Progress::new());
+    assert_eq!(location, Ok(Some((10000, 10000))));
+}
+
+#[test]
+fn finds_a_gap_on_the_edge() {
+    // Covers all of row 0 but its last column.
+    let sensors =
+        Puzzle::parse("Sensor at x=-100, y=10: closest beacon is at x=29, y=10\n").unwrap();
+    let location = day::find_distress_beacon_location_with(&sensors, (0, 20), &Progress::new());
+    assert_eq!(location, Ok(Some((20, 0))));
+}
+
+#[test]
+fn stops_when_cancelled() {
+    let sensors = Puzzle::parse(FAR).unwrap();
+    let progress = Progress::new().on_update(|_| false);
+    assert_eq!(
+        day::find_distress_beacon_location_with(&sensors, (0, 20000), &progress),
+        Err(SolveError::Cancelled(String::from(
+            "scanned 4096 of 20001 rows without finding the beacon"
+        )))
+    );
+}
+
+#[test]
+fn part2_reports_cancellation() {
+    let sensors = Puzzle::parse(FAR).unwrap();
+    let config = Config {
+        max_coordinate: 20000,
+        ..Config::default()
+    };
+    let progress = Progress::new();
+    progress.cancel();
+    assert!(matches!(
+        Puzzle::part2_with(&sensors, &config, &progress),
+        Err(SolveError::Cancelled(_))
+    ));
+}
@@ -0,0 +1,176 @@
+//! Reassigning items so no elf carries much more than the rest.
+//!
+//! This is multiprocessor scheduling: minimize the largest total over a fixed
+//! number of elves. Longest-processing-time greedy gets within a third of the
+//! best; small inventories are then searched exactly with branch and bound,
+//! starting from the better of the greedy plan and the current one.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Most items searched exactly; larger inventories keep the greedy plan.
+pub const EXACT_ITEMS: usize = 24;
+
+/// Most placements the exact search tries before settling for the best plan
+/// it has found.
+const SEARCH_BUDGET: u64 = 1_000_000;
+
+/// A new assignment of items to elves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// The items each elf carries under the plan, largest first.
+    pub elves: Vec<Vec<i64>>,
+    /// The largest total under the plan.
+    pub max: i64,
+    /// The largest total as the items are carried now.
+    pub current_max: i64,
+    /// Whether no plan has a smaller largest total.
+    pub exact: bool,
+}
+
+impl Plan {
+    /// How much the heaviest elf's load goes down.
+    pub fn improvement(&self) -> i64 {
+        self.current_max - self.max
+    }
+
+    pub fn totals(&self) -> Vec<i64> {
+        self.elves.iter().map(|items| items.iter().sum()).collect()
+    }
+}
+
+/// Places each item, largest first, on the elf carrying least; ties go to
+/// the earlier elf. Returns the elf for each item of `sorted`.
+fn greedy(sorted: &[i64], elves: usize) -> Vec<usize> {
+    let mut loads = (0..elves)
+        .map(|elf| Reverse((0, elf)))
+        .collect::<BinaryHeap<Reverse<(i64, usize)>>>();
+    sorted
+        .iter()
+        .map(|&item| {
+            let Reverse((load, elf)) = loads.pop().expect("at least one elf");
+            loads.push(Reverse((load + item, elf)));
+            elf
+        })
+        .collect()
+}
+
+fn max_load(sorted: &[i64], assignment: &[usize], elves: usize) -> i64 {
+    let mut loads = vec![0; elves];
+    for (&item, &elf) in sorted.iter().zip(assignment) {
+        loads[elf] += item;
+    }
+    loads.into_iter().max().unwrap_or(0)
+}
+
+/// Depth-first search over placements of `sorted`, largest first, pruning
+/// any branch that can't beat `best`.
+struct Search<'a> {
+    sorted: &'a [i64],
+    /// No plan can do better than this.
+    bound: i64,
+    loads: Vec<i64>,
+    assignment: Vec<usize>,
+    best: i64,
+    best_assignment: Vec<usize>,
+    budget: u64,
+}
+
+impl Search<'_> {
+    /// Whether the search finished rather than running out of budget.
+    fn place(&mut self, i: usize, max: i64) -> bool {
+        if self.best == self.bound {
+            return true;
+        }
+        if i == self.sorted.len() {
+            if max < self.best {
+                self.best = max;
+                self.best_assignment = self.assignment.clone();
+            }
+            return true;
+        }
+        let item = self.sorted[i];
+        for elf in 0..self.loads.len() {
+            // Elves with the same load are interchangeable.
+            if self.loads[..elf].contains(&self.loads[elf]) {
+                continue;
+            }
+            let load = self.loads[elf] + item;
+            if load.max(max) >= self.best {
+                continue;
+            }
+            if self.budget == 0 {
+                return false;
+            }
+            self.budget -= 1;
+            self.loads[elf] = load;
+            self.assignment[i] = elf;
+            let finished = self.place(i + 1, load.max(max));
+            self.loads[elf] -= item;
+            if !finished {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Spreads every item across `elves` elves to make the largest total as small
+/// as it can. The exact search runs for up to [`EXACT_ITEMS`] items, and only
+/// when no item is negative, since its pruning assumes loads never shrink.
+///
+/// With at least as many elves as carry the items now, the current
+/// assignment is a candidate too, so the plan is never worse than it. Elves
+/// beyond one per item, or per elf there is now, would carry nothing, so the
+/// plan lists at most that many. `None` with no elves to carry anything.
+pub fn plan(items: &[Vec<i64>], elves: usize) -> Option<Plan> {
+    if elves == 0 {
+        return None;
+    }
+    // Each item with the elf carrying it now, largest first.
+    let mut pieces = items
+        .iter()
+        .enumerate()
+        .flat_map(|(elf, items)| items.iter().map(move |&item| (item, elf)))
+        .collect::<Vec<(i64, usize)>>();
+    pieces.sort_by_key(|&(item, _)| Reverse(item));
+    let elves = elves.min(pieces.len().max(items.len()).max(1));
+    let sorted = pieces.iter().map(|&(item, _)| item).collect::<Vec<i64>>();
+    let mut assignment = greedy(&sorted, elves);
+    let mut max = max_load(&sorted, &assignment, elves);
+    if elves >= items.len() {
+        let current = pieces.iter().map(|&(_, elf)| elf).collect::<Vec<usize>>();
+        let current_max = max_load(&sorted, &current, elves);
+        if current_max < max {
+            assignment = current;
+            max = current_max;
+        }
+    }
+    let mut exact = sorted.len() <= EXACT_ITEMS && sorted.iter().all(|&item| item >= 0);
+    if exact {
+        let sum = sorted.iter().sum::<i64>();
+        let share = (sum + elves as i64 - 1) / elves as i64;
+        let mut search = Search {
+            sorted: &sorted,
+            bound: share.max(sorted.first().copied().unwrap_or(0)),
+            loads: vec![0; elves],
+            assignment: vec![0; sorted.len()],
+            best: max,
+            best_assignment: assignment.clone(),
+            budget: SEARCH_BUDGET,
+        };
+        exact = search.place(0, 0);
+        max = search.best;
+        assignment = search.best_assignment;
+    }
+    let mut planned = vec![Vec::new(); elves];
+    for (&item, &elf) in sorted.iter().zip(&assignment) {
+        planned[elf].push(item);
+    }
+    Some(Plan {
+        elves: planned,
+        max,
+        current_max: items.iter().map(|elf| elf.iter().sum()).max().unwrap_or(0),
+        exact,
+    })
+}
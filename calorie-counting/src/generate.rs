@@ -0,0 +1,17 @@
+//! Random inventories: `size` elves carrying one to six snacks each.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..rng.range(1..=6))
+                    .map(|_| format!("{}\n", rng.range(1000..=60000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
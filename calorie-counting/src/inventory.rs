@@ -0,0 +1,277 @@
+//! Inventories in each format the elves hand them in, normalized to one model.
+//!
+//! Three formats are understood:
+//!
+//! - plain: one calorie count per line, elves separated by blank lines, as
+//!   the puzzle gives it;
+//! - CSV: `elf,item,calories` records, optionally under that header, with an
+//!   elf's rows in any order;
+//! - annotated: a `[name]` line opens each elf, followed by its items, each
+//!   either a bare count or `name: calories`.
+//!
+//! Whatever the format, an [`Inventory`] reduces to the groups of calorie
+//! counts the solvers take.
+
+use common::{parse_at, ParseError};
+use std::collections::HashMap;
+
+/// Largest sum of calories, counting every item as positive, an inventory
+/// may hold. Any elf's total, or the sum of any elves', then fits in an `i64`.
+pub const CALORIE_LIMIT: u64 = i64::MAX as u64;
+
+/// Adds `calories` to `carried`, the running sum of every item's magnitude;
+/// `false` once that passes [`CALORIE_LIMIT`].
+pub(crate) fn carry(carried: &mut u64, calories: i64) -> bool {
+    match carried.checked_add(calories.unsigned_abs()) {
+        Some(sum) if sum <= CALORIE_LIMIT => {
+            *carried = sum;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Parses a calorie count at `token`, counting it towards `carried`.
+fn calories(input: &str, token: &str, carried: &mut u64) -> Result<i64, ParseError> {
+    let calories = parse_at(input, token, "a calorie count")?;
+    if !carry(carried, calories) {
+        return Err(ParseError::at(input, token, "calories out of range"));
+    }
+    Ok(calories)
+}
+
+/// How an inventory is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Plain,
+    Csv,
+    Annotated,
+}
+
+impl Format {
+    /// Guesses from the first non-blank line: `[` opens an annotated elf, a
+    /// comma makes CSV, and anything else is plain.
+    pub fn detect(input: &str) -> Format {
+        match input.lines().map(str::trim).find(|l| !l.is_empty()) {
+            Some(l) if l.starts_with('[') => Format::Annotated,
+            Some(l) if l.contains(',') => Format::Csv,
+            _ => Format::Plain,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub name: Option<String>,
+    pub calories: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub name: Option<String>,
+    pub items: Vec<Item>,
+}
+
+impl Elf {
+    pub fn total(&self) -> i64 {
+        self.items.iter().map(|item| item.calories).sum()
+    }
+}
+
+/// Every elf in input order; in CSV, the order each elf first appears.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    /// The calorie counts each elf carries, as [`parse`](crate::parse)
+    /// returns them.
+    pub fn groups(&self) -> Vec<Vec<i64>> {
+        self.elves
+            .iter()
+            .map(|elf| elf.items.iter().map(|item| item.calories).collect())
+            .collect()
+    }
+}
+
+fn unnamed(calories: i64) -> Item {
+    Item {
+        name: None,
+        calories,
+    }
+}
+
+/// Any run of blank lines separates two elves, and blank lines before the
+/// first or after the last are ignored, so every elf carries at least one
+/// item.
+fn parse_plain(input: &str) -> Result<Inventory, ParseError> {
+    let mut elves = Vec::new();
+    let mut items = Vec::new();
+    let mut carried = 0;
+    for l in input.lines() {
+        if l.is_empty() {
+            if !items.is_empty() {
+                elves.push(Elf {
+                    name: None,
+                    items: std::mem::take(&mut items),
+                });
+            }
+        } else {
+            items.push(unnamed(calories(input, l, &mut carried)?));
+        }
+    }
+    if !items.is_empty() {
+        elves.push(Elf { name: None, items });
+    }
+    Ok(Inventory { elves })
+}
+
+/// Splits a CSV record into fields, each as its slice of `line`, to point
+/// errors at, and its value with any quotes undone. Space around a field is
+/// dropped.
+fn fields<'a>(input: &str, line: &'a str) -> Result<Vec<(&'a str, String)>, ParseError> {
+    let mut fields = Vec::new();
+    let mut rest = line;
+    loop {
+        let field = rest.trim_start();
+        let after = match field.strip_prefix('"') {
+            Some(quoted) => {
+                let mut value = String::new();
+                let mut chars = quoted.char_indices().peekable();
+                let close = loop {
+                    match chars.next() {
+                        Some((_, '"')) if chars.peek().is_some_and(|&(_, c)| c == '"') => {
+                            chars.next();
+                            value.push('"');
+                        }
+                        Some((i, '"')) => break i,
+                        Some((_, c)) => value.push(c),
+                        None => {
+                            return Err(ParseError::at(input, field, "unterminated quoted field"))
+                        }
+                    }
+                };
+                fields.push((&field[..close + 2], value));
+                let after = quoted[close + 1..].trim_start();
+                if !after.is_empty() && !after.starts_with(',') {
+                    return Err(ParseError::at(input, after, "expected a comma"));
+                }
+                after
+            }
+            None => {
+                let end = field.find(',').unwrap_or(field.len());
+                let raw = field[..end].trim_end();
+                fields.push((raw, String::from(raw)));
+                &field[end..]
+            }
+        };
+        match after.strip_prefix(',') {
+            Some(next) => rest = next,
+            None => return Ok(fields),
+        }
+    }
+}
+
+fn is_header(fields: &[(&str, String)]) -> bool {
+    let names = fields
+        .iter()
+        .map(|(_, value)| value.to_ascii_lowercase())
+        .collect::<Vec<String>>();
+    names == ["elf", "item", "calories"]
+}
+
+/// `elf,item,calories` records. Rows for the same elf are gathered wherever
+/// they appear; an empty item name leaves the item unnamed.
+fn parse_csv(input: &str) -> Result<Inventory, ParseError> {
+    let mut elves = Vec::<Elf>::new();
+    let mut index = HashMap::new();
+    let mut carried = 0;
+    let mut records = input.lines().filter(|l| !l.trim().is_empty()).peekable();
+    if let Some(&first) = records.peek() {
+        if is_header(&fields(input, first)?) {
+            records.next();
+        }
+    }
+    for l in records {
+        let record = fields(input, l)?;
+        let [(elf_raw, elf), (_, item), (calories_raw, calories)] = record.as_slice() else {
+            return Err(ParseError::at(input, l, "expected elf,item,calories"));
+        };
+        if elf.is_empty() {
+            return Err(ParseError::at(input, elf_raw, "expected an elf name"));
+        }
+        let calories = calories
+            .trim()
+            .parse()
+            .map_err(|_| ParseError::at(input, calories_raw, "expected a calorie count"))?;
+        if !carry(&mut carried, calories) {
+            return Err(ParseError::at(input, calories_raw, "calories out of range"));
+        }
+        let at = *index.entry(elf.clone()).or_insert_with(|| {
+            elves.push(Elf {
+                name: Some(elf.clone()),
+                items: Vec::new(),
+            });
+            elves.len() - 1
+        });
+        elves[at].items.push(Item {
+            name: Some(item.clone()).filter(|name| !name.is_empty()),
+            calories,
+        });
+    }
+    Ok(Inventory { elves })
+}
+
+/// `[name]` headers, each followed by its elf's items. Blank lines are
+/// ignored, and an elf may carry nothing.
+fn parse_annotated(input: &str) -> Result<Inventory, ParseError> {
+    let mut elves = Vec::<Elf>::new();
+    let mut carried = 0;
+    for l in input.lines() {
+        let l = l.trim();
+        if l.is_empty() {
+            continue;
+        }
+        if let Some(header) = l.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .ok_or_else(|| ParseError::at(input, l, "expected a header like \"[name]\""))?
+                .trim();
+            if name.is_empty() {
+                return Err(ParseError::at(input, l, "expected an elf name"));
+            }
+            elves.push(Elf {
+                name: Some(String::from(name)),
+                items: Vec::new(),
+            });
+            continue;
+        }
+        let elf = elves
+            .last_mut()
+            .ok_or_else(|| ParseError::at(input, l, "expected a header like \"[name]\""))?;
+        // Item names may contain colons; the count follows the last one.
+        let (name, token) = match l.rsplit_once(':') {
+            Some((name, token)) => (name.trim(), token.trim()),
+            None => ("", l),
+        };
+        elf.items.push(Item {
+            name: Some(String::from(name)).filter(|name| !name.is_empty()),
+            calories: calories(input, token, &mut carried)?,
+        });
+    }
+    Ok(Inventory { elves })
+}
+
+pub fn parse_as(input: &str, format: Format) -> Result<Inventory, ParseError> {
+    match format {
+        Format::Plain => parse_plain(input),
+        Format::Csv => parse_csv(input),
+        Format::Annotated => parse_annotated(input),
+    }
+}
+
+/// Parses `input` in whichever format [`Format::detect`] finds.
+pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+    parse_as(input, Format::detect(input))
+}
@@ -0,0 +1,192 @@
+use common::{Answer, ParseError, Solution, SolveError};
+use serde::Deserialize;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+pub mod balance;
+mod generate;
+pub mod inventory;
+mod reference;
+pub mod stats;
+pub mod stream;
+
+/// Puzzle constants, overridable through `common::config`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many of the best-stocked elves part 2 sums.
+    pub top_k: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { top_k: 3 }
+    }
+}
+
+/// One of the best-stocked elves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedElf {
+    /// Position in the input, counting from 0.
+    pub elf: usize,
+    pub total: i64,
+    pub items: Vec<i64>,
+}
+
+/// Orders elves best first: the higher total, then the earlier elf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rank {
+    total: i64,
+    elf: usize,
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total
+            .cmp(&other.total)
+            .then_with(|| other.elf.cmp(&self.elf))
+    }
+}
+
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An elf in the running for the top `k`, ordered by rank alone.
+#[derive(Debug)]
+struct Candidate {
+    rank: Rank,
+    items: Vec<i64>,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank
+    }
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The best `k` elves seen so far, worst on top of the heap so it can be
+/// bumped.
+#[derive(Debug)]
+struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<Candidate>>,
+}
+
+impl TopK {
+    /// Starts empty rather than reserving `k`, which may be far more elves
+    /// than there are.
+    fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Considers an elf; `items` is only called if it makes the cut.
+    fn offer(&mut self, elf: usize, total: i64, items: impl FnOnce() -> Vec<i64>) {
+        let rank = Rank { total, elf };
+        let makes_cut = match self.heap.peek() {
+            _ if self.k == 0 => false,
+            Some(Reverse(worst)) if self.heap.len() == self.k => rank > worst.rank,
+            _ => true,
+        };
+        if makes_cut {
+            self.heap.push(Reverse(Candidate {
+                rank,
+                items: items(),
+            }));
+            if self.heap.len() > self.k {
+                self.heap.pop();
+            }
+        }
+    }
+
+    /// Best first.
+    fn into_ranking(self) -> Vec<RankedElf> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(candidate)| RankedElf {
+                elf: candidate.rank.elf,
+                total: candidate.rank.total,
+                items: candidate.items,
+            })
+            .collect()
+    }
+}
+
+/// The `k` best-stocked elves from most calories to least. Ties go to the
+/// elf that comes first, so the ranking is stable.
+///
+/// Only `k` elves are held at once, so this stays cheap for millions of them.
+pub fn rank_elves(items: &[Vec<i64>], k: usize) -> Vec<RankedElf> {
+    let mut top = TopK::new(k);
+    for (elf, subsection) in items.iter().enumerate() {
+        top.offer(elf, subsection.iter().sum(), || subsection.clone());
+    }
+    top.into_ranking()
+}
+
+/// 0 when there are no elves at all.
+pub fn find_max_calorie_elf(items: &[Vec<i64>]) -> i64 {
+    rank_elves(items, 1).first().map_or(0, |elf| elf.total)
+}
+
+pub fn find_top_k_calorie_elves(items: &[Vec<i64>], k: usize) -> i64 {
+    rank_elves(items, k).iter().map(|elf| elf.total).sum()
+}
+
+/// One group of calorie counts per elf, from input in any
+/// [`inventory::Format`]. In the plain format any run of blank lines
+/// separates two elves, and blank lines before the first or after the last
+/// are ignored, so every elf carries at least one item.
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    inventory::parse(input).map(|inventory| inventory.groups())
+}
+
+pub fn part1(items: &[Vec<i64>]) -> i64 {
+    find_max_calorie_elf(items)
+}
+
+pub fn part2(items: &[Vec<i64>], config: &Config) -> i64 {
+    find_top_k_calorie_elves(items, config.top_k)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+    const NAME: &'static str = "calorie-counting";
+
+    type Input = Vec<Vec<i64>>;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(items: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(items).into())
+    }
+
+    fn part2(items: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(items, config).into())
+    }
+}
@@ -1,38 +1,181 @@
-use std::io::{self, BufRead};
+use calorie_counting::balance;
+use calorie_counting::stats::Stats;
+use calorie_counting::stream::{self, Summary};
+use calorie_counting::{Config, Puzzle};
+use common::cli;
+use common::config::Overrides;
+use common::{read_stdin, OrExit, Solution};
+use std::env;
+use std::io;
+use std::process;
 
-fn find_max_calorie_elf(items: &Vec<Vec<i64>>) -> i64 {
-    let v: Vec<i64> = items
-        .iter()
-        .map(|subsection| subsection.iter().sum())
-        .collect();
-    *v.iter().max().unwrap()
+const USAGE: &str = "usage: calorie-counting [options] < input
+       calorie-counting top [-k N] [--config FILE] [--set KEY=VALUE]... < input
+       calorie-counting stream [-k N] [--config FILE] [--set KEY=VALUE]... < input
+       calorie-counting stats [--bins N] < input
+       calorie-counting balance [-n ELVES] < input";
+
+/// Histogram bars `stats` draws unless told otherwise.
+const DEFAULT_BINS: usize = 10;
+
+fn parse_k_args<I: Iterator<Item = String>>(mut args: I) -> Result<Config, String> {
+    let mut overrides = Overrides::new();
+    while let Some(arg) = args.next() {
+        if cli::parse_config_arg(&arg, &mut args, &mut overrides)? {
+            continue;
+        }
+        match arg.as_str() {
+            "-k" => {
+                let k = args.next().ok_or("missing value for -k")?;
+                overrides.set(&format!("top_k={}", k))?;
+            }
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+    overrides.resolve::<Config>()
 }
 
-fn find_top_k_calorie_elves(items: &Vec<Vec<i64>>, k: usize) -> i64 {
-    let mut v: Vec<i64> = items
-        .iter()
-        .map(|subsection| subsection.iter().sum())
-        .collect();
-    v.sort_by(|a, b| b.cmp(a));
-    v[0..k].iter().sum()
+/// Reads stdin in any format, keeping only the best `top_k` elves; plain
+/// inventories are read a line at a time.
+fn aggregate_stdin<I: Iterator<Item = String>>(args: I) -> (Config, Summary) {
+    let config = parse_k_args(args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let summary = stream::aggregate(io::stdin().lock(), config.top_k).or_exit();
+    (config, summary)
 }
 
-fn main() {
-    let stdin = io::stdin();
-    let mut items = vec![vec![]];
-    for line in stdin.lock().lines() {
-        let l = line.unwrap();
-        if l.len() == 0 {
-            items.push(vec![]);
-        } else {
-            let idx = items.len() - 1;
-            items[idx].push(l.parse::<i64>().unwrap());
+/// `calorie-counting top`: lists the `top_k` best-stocked elves, numbered from
+/// 1 as the puzzle counts them.
+fn top<I: Iterator<Item = String>>(args: I) {
+    let (_, summary) = aggregate_stdin(args);
+    for (rank, elf) in summary.top.iter().enumerate() {
+        let items = elf
+            .items
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<String>>();
+        println!(
+            "{}. Elf {}: {} ({})",
+            rank + 1,
+            elf.elf + 1,
+            elf.total,
+            items.join(" + ")
+        );
+    }
+}
+
+/// `calorie-counting stream`: both answers without holding the inventory.
+fn stream<I: Iterator<Item = String>>(args: I) {
+    let (config, summary) = aggregate_stdin(args);
+    println!("Max calorie elf: {}", summary.max());
+    println!(
+        "Sum of calories of top {} elves: {}",
+        config.top_k,
+        summary.top_total()
+    );
+}
+
+fn parse_bins<I: Iterator<Item = String>>(mut args: I) -> Result<usize, String> {
+    let mut bins = DEFAULT_BINS;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bins" => {
+                let value = args.next().ok_or("missing value for --bins")?;
+                bins = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid bin count: {}", value)),
+                };
+            }
+            _ => return Err(format!("unexpected argument: {}", arg)),
         }
     }
+    Ok(bins)
+}
 
-    println!("Max calorie elf: {}", find_max_calorie_elf(&items));
+/// `calorie-counting stats`: how totals and item counts are spread across
+/// every elf, with a histogram of totals.
+fn stats<I: Iterator<Item = String>>(args: I) {
+    let bins = parse_bins(args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    });
+    let items = Puzzle::parse(&read_stdin()).or_exit();
+    match Stats::new(&items, bins) {
+        Some(stats) => print!("{}", stats),
+        None => println!("No elves"),
+    }
+}
+
+/// `calorie-counting balance`: moves items between elves so the heaviest
+/// load is as light as it can be, by default across the same number of elves.
+fn balance<I: Iterator<Item = String>>(mut args: I) {
+    let mut elves = None;
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "-n" => args
+                .next()
+                .ok_or_else(|| String::from("missing value for -n"))
+                .and_then(|value| match value.parse::<usize>() {
+                    Ok(n) if n > 0 => Ok(n),
+                    _ => Err(format!("invalid elf count: {}", value)),
+                }),
+            _ => Err(format!("unexpected argument: {}", arg)),
+        };
+        match parsed {
+            Ok(n) => elves = Some(n),
+            Err(e) => {
+                eprintln!("{}\n{}", e, USAGE);
+                process::exit(2);
+            }
+        }
+    }
+    let items = Puzzle::parse(&read_stdin()).or_exit();
+    let plan = match balance::plan(&items, elves.unwrap_or(items.len())) {
+        Some(plan) => plan,
+        None => {
+            println!("No elves");
+            return;
+        }
+    };
+    println!("Current max: {}", plan.current_max);
     println!(
-        "Sum of calories of top 3 elves: {}",
-        find_top_k_calorie_elves(&items, 3)
+        "Planned max: {} ({})",
+        plan.max,
+        if plan.exact { "optimal" } else { "best found" }
     );
+    println!("Improvement: {}", plan.improvement());
+    for (elf, (items, total)) in plan.elves.iter().zip(plan.totals()).enumerate() {
+        let items = items.iter().map(i64::to_string).collect::<Vec<String>>();
+        println!("Elf {}: {} ({})", elf + 1, total, items.join(" + "));
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("top") => {
+            args.next();
+            top(args);
+        }
+        Some("stream") => {
+            args.next();
+            stream(args);
+        }
+        Some("stats") => {
+            args.next();
+            stats(args);
+        }
+        Some("balance") => {
+            args.next();
+            balance(args);
+        }
+        _ => cli::run_with::<Puzzle>(|config| {
+            [
+                String::from("Max calorie elf"),
+                format!("Sum of calories of top {} elves", config.top_k),
+            ]
+        }),
+    }
 }
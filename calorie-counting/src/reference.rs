@@ -0,0 +1,43 @@
+//! Totals every elf and picks the best ones one at a time.
+
+use crate::Puzzle;
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+fn totals(items: &[Vec<i64>]) -> Vec<i64> {
+    items
+        .iter()
+        .map(|elf| {
+            let mut total = 0;
+            for calories in elf {
+                total += calories;
+            }
+            total
+        })
+        .collect()
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(items: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let mut best = i64::MIN;
+        for total in totals(items) {
+            if total > best {
+                best = total;
+            }
+        }
+        Ok(best.into())
+    }
+
+    fn reference_part2(items: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let mut remaining = totals(items);
+        let mut sum = 0;
+        for _ in 0..config.top_k {
+            let best = match (0..remaining.len()).max_by_key(|&i| remaining[i]) {
+                Some(best) => best,
+                None => break,
+            };
+            sum += remaining.remove(best);
+        }
+        Ok(sum.into())
+    }
+}
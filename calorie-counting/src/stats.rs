@@ -0,0 +1,176 @@
+//! How the calories are spread across elves, beyond the best few.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The percentiles a report lists.
+pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+/// Longest bar in a drawn histogram, in characters.
+const BAR_WIDTH: usize = 40;
+
+/// Elves whose totals fall in `start..=end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bin {
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+/// The distribution of elf totals and of items per elf.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub items: usize,
+    pub min: i64,
+    pub max: i64,
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation of the totals.
+    pub std_dev: f64,
+    /// Each of [`PERCENTILES`] with its total, interpolating between elves.
+    pub percentiles: Vec<(u8, f64)>,
+    /// How many elves carry each number of items.
+    pub items_per_elf: BTreeMap<usize, usize>,
+    /// Equal-width bins of totals, lowest first.
+    pub histogram: Vec<Bin>,
+}
+
+/// The `p`th percentile of `sorted`, interpolating linearly between the
+/// closest ranks.
+fn percentile(sorted: &[i64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+    let fraction = rank - below as f64;
+    sorted[below] as f64 + (sorted[above] as f64 - sorted[below] as f64) * fraction
+}
+
+/// Splits `min..=max` into at most `bins` equal widths and counts `sorted`
+/// into them. Works in `i128`, since the span of two `i64` totals may not fit
+/// in one.
+fn histogram(sorted: &[i64], bins: usize, min: i64, max: i64) -> Vec<Bin> {
+    let span = max.abs_diff(min) as i128 + 1;
+    let bins = (bins.max(1) as i128).min(span);
+    let width = (span + bins - 1) / bins;
+    let mut histogram = (0..bins)
+        .map(|i| min as i128 + i * width)
+        .take_while(|&start| start <= max as i128)
+        .map(|start| Bin {
+            start: start as i64,
+            end: (start + width - 1).min(max as i128) as i64,
+            count: 0,
+        })
+        .collect::<Vec<Bin>>();
+    for &total in sorted {
+        histogram[(total.abs_diff(min) as i128 / width) as usize].count += 1;
+    }
+    histogram
+}
+
+impl Stats {
+    /// Summarizes parsed elf groups, drawing the histogram with up to `bins`
+    /// bars. `None` when there are no elves.
+    pub fn new(items: &[Vec<i64>], bins: usize) -> Option<Stats> {
+        let mut totals = items
+            .iter()
+            .map(|elf| elf.iter().sum())
+            .collect::<Vec<i64>>();
+        totals.sort_unstable();
+        let (&min, &max) = (totals.first()?, totals.last()?);
+        let n = totals.len() as f64;
+        let mean = totals.iter().map(|&total| total as f64).sum::<f64>() / n;
+        let variance = totals
+            .iter()
+            .map(|&total| (total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let mut items_per_elf = BTreeMap::new();
+        for elf in items {
+            *items_per_elf.entry(elf.len()).or_insert(0) += 1;
+        }
+        Some(Stats {
+            elves: items.len(),
+            items: items.iter().map(Vec::len).sum(),
+            min,
+            max,
+            mean,
+            median: percentile(&totals, 50.0),
+            std_dev: variance.sqrt(),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&totals, p as f64)))
+                .collect(),
+            items_per_elf,
+            histogram: histogram(&totals, bins, min, max),
+        })
+    }
+}
+
+/// `n` with the noun that fits it, as in "1 elf" or "2 elves".
+fn count(n: usize, one: &str, many: &str) -> String {
+    match n {
+        1 => format!("{} {}", n, one),
+        _ => format!("{} {}", n, many),
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} carrying {}",
+            count(self.elves, "elf", "elves"),
+            count(self.items, "item", "items")
+        )?;
+        writeln!(
+            f,
+            "Totals: min {}, max {}, mean {:.1}, median {:.1}, std dev {:.1}",
+            self.min, self.max, self.mean, self.median, self.std_dev
+        )?;
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(p, total)| format!("p{} {:.1}", p, total))
+            .collect::<Vec<String>>();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+        let items_per_elf = self
+            .items_per_elf
+            .iter()
+            .map(|(&items, &elves)| {
+                format!(
+                    "{}: {}",
+                    count(items, "item", "items"),
+                    count(elves, "elf", "elves")
+                )
+            })
+            .collect::<Vec<String>>();
+        writeln!(f, "Items per elf: {}", items_per_elf.join(", "))?;
+        writeln!(f, "Histogram:")?;
+        let most = self
+            .histogram
+            .iter()
+            .map(|bin| bin.count)
+            .max()
+            .unwrap_or(0);
+        let digits = |n: i64| n.to_string().len();
+        let start_width = self.histogram.iter().map(|bin| digits(bin.start)).max();
+        let end_width = self.histogram.iter().map(|bin| digits(bin.end)).max();
+        for bin in &self.histogram {
+            // Any non-empty bin gets at least one mark.
+            let bar = (bin.count * BAR_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>sw$}..={:<ew$} | {:<bw$} {}",
+                bin.start,
+                bin.end,
+                "#".repeat(bar),
+                bin.count,
+                sw = start_width.unwrap_or(0),
+                ew = end_width.unwrap_or(0),
+                bw = BAR_WIDTH
+            )?;
+        }
+        Ok(())
+    }
+}
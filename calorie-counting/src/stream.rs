@@ -0,0 +1,179 @@
+//! Totals straight from a reader, for inventories too large to hold at once.
+//!
+//! An [`Aggregator`] takes one line at a time and keeps only the elf being
+//! read and the best `k` so far. It reads the plain format and splits elves
+//! exactly as [`parse`](crate::parse) does, so elf numbers agree. CSV and
+//! annotated inventories, where an elf's items may be spread across the input,
+//! are read whole instead.
+
+use crate::inventory::{self, carry, Format};
+use crate::{rank_elves, RankedElf, TopK};
+use common::ParseError;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// What a whole inventory came to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub elves: usize,
+    pub items: usize,
+    /// The best `k` elves, best first.
+    pub top: Vec<RankedElf>,
+}
+
+impl Summary {
+    /// The most calories any elf carries; 0 with no elves.
+    pub fn max(&self) -> i64 {
+        self.top.first().map_or(0, |elf| elf.total)
+    }
+
+    /// The calories the best `k` elves carry between them.
+    pub fn top_total(&self) -> i64 {
+        self.top.iter().map(|elf| elf.total).sum()
+    }
+}
+
+/// Why an inventory couldn't be aggregated.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "unable to read input: {}", e),
+            ReadError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// Running totals over an inventory fed a line at a time.
+#[derive(Debug)]
+pub struct Aggregator {
+    line: usize,
+    elves: usize,
+    items: usize,
+    current: Vec<i64>,
+    total: i64,
+    /// Every item's magnitude so far, against [`CALORIE_LIMIT`](inventory::CALORIE_LIMIT).
+    carried: u64,
+    top: TopK,
+}
+
+impl Aggregator {
+    /// Keeps the best `k` elves.
+    pub fn new(k: usize) -> Self {
+        Aggregator {
+            line: 0,
+            elves: 0,
+            items: 0,
+            current: Vec::new(),
+            total: 0,
+            carried: 0,
+            top: TopK::new(k),
+        }
+    }
+
+    /// Takes the next line, without its line ending. Errors point at the
+    /// line as [`parse`](crate::parse) would.
+    pub fn push_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.line += 1;
+        if line.is_empty() {
+            self.end_elf();
+            return Ok(());
+        }
+        let at = self.line;
+        let error = |message: &str| ParseError {
+            line: at,
+            column: 1,
+            text: String::from(line),
+            message: String::from(message),
+        };
+        let calories = line
+            .parse::<i64>()
+            .map_err(|_| error("expected a calorie count"))?;
+        if !carry(&mut self.carried, calories) {
+            return Err(error("calories out of range"));
+        }
+        self.current.push(calories);
+        self.total += calories;
+        self.items += 1;
+        Ok(())
+    }
+
+    /// Closes the elf being read, if it has any items; blank lines in a row
+    /// end only one elf.
+    fn end_elf(&mut self) {
+        if self.current.is_empty() {
+            return;
+        }
+        let current = &self.current;
+        self.top.offer(self.elves, self.total, || current.clone());
+        self.elves += 1;
+        self.total = 0;
+        self.current.clear();
+    }
+
+    /// Counts the last elf, which needs no blank line after it.
+    pub fn finish(mut self) -> Summary {
+        self.end_elf();
+        Summary {
+            elves: self.elves,
+            items: self.items,
+            top: self.top.into_ranking(),
+        }
+    }
+}
+
+/// Summarizes elves already parsed into groups.
+fn summarize(groups: &[Vec<i64>], k: usize) -> Summary {
+    Summary {
+        elves: groups.len(),
+        items: groups.iter().map(Vec::len).sum(),
+        top: rank_elves(groups, k),
+    }
+}
+
+/// Aggregates every line of `reader`, keeping the best `k` elves. The first
+/// non-blank line picks the [`Format`]; only plain inventories stream.
+pub fn aggregate(mut reader: impl BufRead, k: usize) -> Result<Summary, ReadError> {
+    let mut aggregator = Aggregator::new(k);
+    let mut line = String::new();
+    let mut detected = false;
+    while reader.read_line(&mut line)? > 0 {
+        if !detected && !line.trim().is_empty() {
+            detected = true;
+            let format = Format::detect(&line);
+            if format != Format::Plain {
+                // Blank lines stand in for those already read, so errors
+                // still point at the right line.
+                let mut input = "\n".repeat(aggregator.line);
+                input.push_str(&line);
+                reader.read_to_string(&mut input)?;
+                let inventory = inventory::parse_as(&input, format)?;
+                return Ok(summarize(&inventory.groups(), k));
+            }
+        }
+        let content = line.strip_suffix('\n').unwrap_or(&line);
+        aggregator.push_line(content.strip_suffix('\r').unwrap_or(content))?;
+        line.clear();
+    }
+    Ok(aggregator.finish())
+}
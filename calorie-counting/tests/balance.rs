@@ -0,0 +1,136 @@
+use calorie_counting::balance::{self, Plan, EXACT_ITEMS};
+use calorie_counting::Puzzle;
+use common::Solution;
+use proptest::prelude::*;
+
+const EXAMPLE: &str = include_str!("../fixtures/example/input.txt");
+
+/// The smallest largest total over every way to hand out `items`.
+fn brute_force(items: &[i64], elves: usize) -> i64 {
+    fn go(items: &[i64], loads: &mut Vec<i64>) -> i64 {
+        match items.split_first() {
+            None => loads.iter().copied().max().unwrap_or(0),
+            Some((&item, rest)) => (0..loads.len())
+                .map(|elf| {
+                    loads[elf] += item;
+                    let best = go(rest, loads);
+                    loads[elf] -= item;
+                    best
+                })
+                .min()
+                .unwrap(),
+        }
+    }
+    go(items, &mut vec![0; elves])
+}
+
+fn sorted_items(plan: &Plan) -> Vec<i64> {
+    let mut items = plan.elves.concat();
+    items.sort_unstable();
+    items
+}
+
+#[test]
+fn evens_out_the_example() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    let plan = balance::plan(&items, 5).unwrap();
+    assert_eq!(plan.current_max, 24000);
+    assert_eq!(plan.max, 11000);
+    assert_eq!(plan.improvement(), 13000);
+    assert!(plan.exact);
+    assert_eq!(plan.totals(), vec![11000; 5]);
+}
+
+#[test]
+fn beats_the_greedy_plan() {
+    // Largest first puts 3 + 2 + 2 on one elf.
+    let plan = balance::plan(&[vec![3, 3, 2, 2, 2]], 2).unwrap();
+    assert_eq!(plan.max, 6);
+    assert!(plan.exact);
+    assert_eq!(plan.elves, vec![vec![3, 3], vec![2, 2, 2]]);
+}
+
+#[test]
+fn large_inventories_keep_the_greedy_plan() {
+    let items = (1..=EXACT_ITEMS as i64 + 1)
+        .map(|n| vec![n * 100])
+        .collect::<Vec<Vec<i64>>>();
+    let plan = balance::plan(&items, 4).unwrap();
+    assert!(!plan.exact);
+    assert_eq!(plan.elves.len(), 4);
+    assert_eq!(plan.totals().into_iter().max(), Some(plan.max));
+}
+
+#[test]
+fn negative_items_keep_the_greedy_plan() {
+    let plan = balance::plan(&[vec![5, -2], vec![4]], 2).unwrap();
+    assert!(!plan.exact);
+    assert_eq!(sorted_items(&plan), vec![-2, 4, 5]);
+}
+
+#[test]
+fn never_worse_than_now() {
+    let plan = balance::plan(&[vec![5, -3], vec![4]], 2).unwrap();
+    assert_eq!((plan.current_max, plan.max), (4, 4));
+    assert_eq!(plan.improvement(), 0);
+
+    // Too many items to search, and the greedy plan puts 3 + 2 + 2 together.
+    let mut three = vec![3, 3];
+    three.resize(12, 0);
+    let mut two = vec![2, 2, 2];
+    two.resize(13, 0);
+    let plan = balance::plan(&[three, two], 2).unwrap();
+    assert!(!plan.exact);
+    assert_eq!((plan.current_max, plan.max), (6, 6));
+    assert_eq!(plan.totals(), vec![6, 6]);
+}
+
+#[test]
+fn extra_elves_carry_nothing() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    let plan = balance::plan(&items, 100_000_000_000).unwrap();
+    assert_eq!(plan.elves.len(), 10);
+    assert_eq!(plan.max, 10000);
+    assert!(plan.exact);
+}
+
+#[test]
+fn needs_an_elf() {
+    assert_eq!(balance::plan(&[vec![1000]], 0), None);
+    let plan = balance::plan(&[], 3).unwrap();
+    assert_eq!((plan.max, plan.current_max), (0, 0));
+}
+
+proptest! {
+    #[test]
+    fn exact_plans_are_optimal(
+        items in prop::collection::vec(prop::collection::vec(0i64..50, 1..4), 1..5),
+        elves in 1usize..5,
+    ) {
+        let plan = balance::plan(&items, elves).unwrap();
+        let flat = items.concat();
+        prop_assume!(flat.len() <= 9);
+        prop_assert!(plan.exact);
+        prop_assert_eq!(plan.max, brute_force(&flat, elves));
+    }
+
+    #[test]
+    fn plans_keep_every_item(
+        items in prop::collection::vec(prop::collection::vec(0i64..10_000, 1..6), 1..20),
+        elves in 1usize..8,
+    ) {
+        let plan = balance::plan(&items, elves).unwrap();
+        let mut flat = items.concat();
+        flat.sort_unstable();
+        prop_assert_eq!(sorted_items(&plan), flat.clone());
+        prop_assert_eq!(plan.elves.len(), elves.min(flat.len()));
+        if elves >= items.len() {
+            prop_assert!(plan.max <= plan.current_max);
+        }
+        prop_assert_eq!(plan.totals().into_iter().max(), Some(plan.max));
+        // No elf can carry less than an even share, or less than the
+        // largest item.
+        let share = (flat.iter().sum::<i64>() + elves as i64 - 1) / elves as i64;
+        prop_assert!(plan.max >= share.max(*flat.last().unwrap()));
+    }
+}
@@ -0,0 +1,38 @@
+use calorie_counting::inventory::{self, Format};
+use calorie_counting::stream::{self, ReadError};
+use calorie_counting::Puzzle;
+use common::Solution;
+
+/// Each count fits, but two elves' totals together would not.
+const OVERFLOWING: &str = "-9000000000000000000\n\n9000000000000000000\n";
+
+#[test]
+fn rejects_inventories_that_overflow_a_total() {
+    let e = Puzzle::parse(OVERFLOWING).unwrap_err();
+    assert_eq!((e.line, e.message.as_str()), (3, "calories out of range"));
+
+    let e = match stream::aggregate(OVERFLOWING.as_bytes(), 3) {
+        Err(ReadError::Parse(e)) => e,
+        other => panic!("expected a parse error, got {:?}", other),
+    };
+    assert_eq!((e.line, e.message.as_str()), (3, "calories out of range"));
+
+    let csv = format!("a,x,{}\nb,y,1\n", i64::MAX);
+    let e = inventory::parse_as(&csv, Format::Csv).unwrap_err();
+    assert_eq!((e.line, e.text.as_str()), (2, "1"));
+
+    let annotated = format!("[a]\n{}\n[b]\ny: -1\n", i64::MAX);
+    let e = inventory::parse_as(&annotated, Format::Annotated).unwrap_err();
+    assert_eq!((e.line, e.text.as_str()), (4, "-1"));
+}
+
+#[test]
+fn solves_at_the_calorie_limit() {
+    let input = format!("{}\n", i64::MAX);
+    let items = Puzzle::parse(&input).unwrap();
+    let config = Default::default();
+    assert_eq!(Puzzle::part1(&items, &config).unwrap(), i64::MAX.into());
+    assert_eq!(Puzzle::part2(&items, &config).unwrap(), i64::MAX.into());
+    let summary = stream::aggregate(input.as_bytes(), 3).unwrap();
+    assert_eq!(summary.top_total(), i64::MAX);
+}
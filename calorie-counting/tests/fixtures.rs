@@ -0,0 +1,6 @@
+use calorie_counting::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,19 @@
+use calorie_counting::{self as day, Config, Puzzle};
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..40) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let config = Config::default();
+        prop_assert_eq!(input.len(), size);
+        let best = day::part1(&input);
+        let top = day::part2(&input, &config);
+        prop_assert!(best <= top);
+        prop_assert!(top <= best * config.top_k as i64);
+    }
+}
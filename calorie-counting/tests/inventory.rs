@@ -0,0 +1,150 @@
+use calorie_counting::inventory::{self, Elf, Format, Item};
+use calorie_counting::{self as day, Puzzle};
+use common::{ParseError, Solution};
+use proptest::prelude::*;
+
+const EXAMPLE: &str = include_str!("../fixtures/example/input.txt");
+const CSV: &str = include_str!("../fixtures/csv/input.txt");
+const ANNOTATED: &str = include_str!("../fixtures/annotated/input.txt");
+
+fn item(name: &str, calories: i64) -> Item {
+    Item {
+        name: Some(String::from(name)).filter(|name| !name.is_empty()),
+        calories,
+    }
+}
+
+#[test]
+fn detects_each_format() {
+    assert_eq!(Format::detect(EXAMPLE), Format::Plain);
+    assert_eq!(Format::detect(CSV), Format::Csv);
+    assert_eq!(Format::detect(ANNOTATED), Format::Annotated);
+    assert_eq!(Format::detect("\n  \n[Ann]\n1000"), Format::Annotated);
+    assert_eq!(Format::detect("Ann,,1000"), Format::Csv);
+    assert_eq!(Format::detect(""), Format::Plain);
+}
+
+#[test]
+fn every_format_gives_the_same_groups() {
+    let groups = Puzzle::parse(EXAMPLE).unwrap();
+    for input in [CSV, ANNOTATED] {
+        let parsed = Puzzle::parse(input).unwrap();
+        assert_eq!(parsed, groups);
+        assert_eq!(day::find_max_calorie_elf(&parsed), 24000);
+        assert_eq!(day::rank_elves(&parsed, 3), day::rank_elves(&groups, 3));
+    }
+}
+
+#[test]
+fn keeps_names() {
+    let csv = inventory::parse(CSV).unwrap();
+    assert_eq!(csv.elves[1].name.as_deref(), Some("Bo"));
+    assert_eq!(csv.elves[1].items, vec![item("dates, dried", 4000)]);
+    assert_eq!(csv.elves[3].items[2], item("\"instant\" noodles", 9000));
+
+    let annotated = inventory::parse(ANNOTATED).unwrap();
+    assert_eq!(
+        annotated.elves[0],
+        Elf {
+            name: Some(String::from("Ann")),
+            items: vec![item("apple", 1000), item("bread", 2000), item("", 3000)],
+        }
+    );
+    assert_eq!(annotated.elves[3].name.as_deref(), Some("Dee the Elder"));
+    assert_eq!(annotated.elves[3].items[2], item("snack: noodles", 9000));
+    assert_eq!(annotated.elves[3].total(), 24000);
+}
+
+#[test]
+fn gathers_csv_rows_by_elf() {
+    let input = "Ann,apple,1000\nBo,,2000\n\nAnn, bread ,3000\n";
+    let inventory = inventory::parse(input).unwrap();
+    assert_eq!(inventory.groups(), vec![vec![1000, 3000], vec![2000]]);
+    assert_eq!(inventory.elves[0].items[1], item("bread", 3000));
+    assert_eq!(inventory.elves[1].items, vec![item("", 2000)]);
+}
+
+#[test]
+fn annotated_elves_may_carry_nothing() {
+    let inventory = inventory::parse("[Ann]\n\n[Bo]\n: 500\n").unwrap();
+    assert_eq!(inventory.groups(), vec![vec![], vec![500]]);
+    assert_eq!(day::find_max_calorie_elf(&inventory.groups()), 500);
+}
+
+fn error(line: usize, column: usize, text: &str, message: &str) -> ParseError {
+    ParseError {
+        line,
+        column,
+        text: String::from(text),
+        message: String::from(message),
+    }
+}
+
+#[test]
+fn points_at_bad_input() {
+    let cases = [
+        (
+            "Ann,apple,lots",
+            error(1, 11, "lots", "expected a calorie count"),
+        ),
+        (
+            "Ann,apple,1000\nBo,1000",
+            error(2, 1, "Bo,1000", "expected elf,item,calories"),
+        ),
+        (
+            "Ann,apple,1000\n,pear,10",
+            error(2, 1, "", "expected an elf name"),
+        ),
+        (
+            "Ann,\"apple,1000",
+            error(1, 5, "\"apple,1000", "unterminated quoted field"),
+        ),
+        (
+            "Ann,\"apple\"x,1000",
+            error(1, 12, "x,1000", "expected a comma"),
+        ),
+        (
+            "[Ann]\napple: some",
+            error(2, 8, "some", "expected a calorie count"),
+        ),
+        (
+            "[Ann\n1000",
+            error(1, 1, "[Ann", "expected a header like \"[name]\""),
+        ),
+        ("[ ]\n1000", error(1, 1, "[ ]", "expected an elf name")),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(inventory::parse(input), Err(expected), "{:?}", input);
+    }
+    assert_eq!(
+        inventory::parse_as("1000\n[Ann]", Format::Annotated),
+        Err(error(1, 1, "1000", "expected a header like \"[name]\""))
+    );
+}
+
+fn groups() -> impl Strategy<Value = Vec<Vec<i64>>> {
+    prop::collection::vec(prop::collection::vec(-1000i64..100_000, 1..5), 0..20)
+}
+
+proptest! {
+    #[test]
+    fn formats_agree(groups in groups()) {
+        let plain = groups
+            .iter()
+            .map(|elf| elf.iter().map(i64::to_string).collect::<Vec<String>>().join("\n"))
+            .collect::<Vec<String>>()
+            .join("\n\n");
+        let mut csv = String::from("elf,item,calories\n");
+        let mut annotated = String::new();
+        for (elf, items) in groups.iter().enumerate() {
+            annotated += &format!("[Elf {}]\n", elf + 1);
+            for (i, calories) in items.iter().enumerate() {
+                csv += &format!("Elf {},\"item {}\",{}\n", elf + 1, i, calories);
+                annotated += &format!("item {}: {}\n", i, calories);
+            }
+        }
+        prop_assert_eq!(inventory::parse_as(&plain, Format::Plain).unwrap().groups(), groups.clone());
+        prop_assert_eq!(inventory::parse_as(&csv, Format::Csv).unwrap().groups(), groups.clone());
+        prop_assert_eq!(day::parse(&annotated).unwrap(), groups);
+    }
+}
@@ -0,0 +1,80 @@
+use calorie_counting::stream;
+use calorie_counting::{self as day, Puzzle, RankedElf};
+use common::Solution;
+use proptest::prelude::*;
+
+const EXAMPLE: &str = include_str!("../fixtures/example/input.txt");
+
+#[test]
+fn ranks_the_example() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        day::rank_elves(&items, 3),
+        vec![
+            RankedElf {
+                elf: 3,
+                total: 24000,
+                items: vec![7000, 8000, 9000],
+            },
+            RankedElf {
+                elf: 2,
+                total: 11000,
+                items: vec![5000, 6000],
+            },
+            RankedElf {
+                elf: 4,
+                total: 10000,
+                items: vec![10000],
+            },
+        ]
+    );
+}
+
+#[test]
+fn ties_go_to_the_earlier_elf() {
+    let items = vec![vec![5], vec![2, 3], vec![9], vec![1, 4]];
+    let elves = day::rank_elves(&items, 3)
+        .iter()
+        .map(|elf| elf.elf)
+        .collect::<Vec<usize>>();
+    assert_eq!(elves, vec![2, 0, 1]);
+}
+
+#[test]
+fn k_bounds_the_ranking() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(day::rank_elves(&items, 0), vec![]);
+    assert_eq!(day::rank_elves(&items, 100).len(), items.len());
+}
+
+#[test]
+fn huge_k_holds_only_the_elves() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    for k in [100_000_000_000, usize::MAX] {
+        assert_eq!(day::rank_elves(&items, k).len(), items.len());
+        assert_eq!(day::find_top_k_calorie_elves(&items, k), 45000 + 10000);
+        let summary = stream::aggregate(EXAMPLE.as_bytes(), k).unwrap();
+        assert_eq!(summary.top.len(), items.len());
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_a_full_sort(
+        items in prop::collection::vec(prop::collection::vec(0i64..100, 0..4), 1..50),
+        k in 0usize..10,
+    ) {
+        let mut sorted = items
+            .iter()
+            .enumerate()
+            .map(|(elf, items)| (items.iter().sum::<i64>(), elf))
+            .collect::<Vec<(i64, usize)>>();
+        sorted.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        sorted.truncate(k);
+        let ranked = day::rank_elves(&items, k)
+            .iter()
+            .map(|elf| (elf.total, elf.elf))
+            .collect::<Vec<(i64, usize)>>();
+        prop_assert_eq!(ranked, sorted);
+    }
+}
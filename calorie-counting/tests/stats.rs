@@ -0,0 +1,158 @@
+use calorie_counting::stats::{Bin, Stats};
+use calorie_counting::Puzzle;
+use common::Solution;
+use proptest::prelude::*;
+
+const EXAMPLE: &str = include_str!("../fixtures/example/input.txt");
+
+#[test]
+fn summarizes_the_example() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    let stats = Stats::new(&items, 4).unwrap();
+    assert_eq!(stats.elves, 5);
+    assert_eq!(stats.items, 10);
+    assert_eq!((stats.min, stats.max), (4000, 24000));
+    assert_eq!(stats.mean, 11000.0);
+    assert_eq!(stats.median, 10000.0);
+    assert!((stats.std_dev - 6985.699678629192).abs() < 1e-6);
+    assert_eq!(
+        stats.percentiles,
+        vec![
+            (10, 4800.0),
+            (25, 6000.0),
+            (50, 10000.0),
+            (75, 11000.0),
+            (90, 18800.0),
+            (99, 23480.0)
+        ]
+    );
+    let items_per_elf = stats
+        .items_per_elf
+        .iter()
+        .map(|(&items, &elves)| (items, elves))
+        .collect::<Vec<(usize, usize)>>();
+    assert_eq!(items_per_elf, vec![(1, 2), (2, 1), (3, 2)]);
+    assert_eq!(
+        stats.histogram,
+        vec![
+            Bin {
+                start: 4000,
+                end: 9000,
+                count: 2
+            },
+            Bin {
+                start: 9001,
+                end: 14001,
+                count: 2
+            },
+            Bin {
+                start: 14002,
+                end: 19002,
+                count: 0
+            },
+            Bin {
+                start: 19003,
+                end: 24000,
+                count: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn draws_the_report() {
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    let report = Stats::new(&items, 4).unwrap().to_string();
+    let lines = report.lines().collect::<Vec<&str>>();
+    assert_eq!(lines[0], "5 elves carrying 10 items");
+    assert_eq!(
+        lines[1],
+        "Totals: min 4000, max 24000, mean 11000.0, median 10000.0, std dev 6985.7"
+    );
+    assert_eq!(
+        lines[3],
+        "Items per elf: 1 item: 2 elves, 2 items: 1 elf, 3 items: 2 elves"
+    );
+    assert_eq!(lines[4], "Histogram:");
+    assert_eq!(
+        lines[5],
+        format!(" 4000..=9000  | {:<40} 2", "#".repeat(40))
+    );
+    assert_eq!(lines[7], format!("14002..=19002 | {:<40} 0", ""));
+    assert_eq!(
+        lines[8],
+        format!("19003..=24000 | {:<40} 1", "#".repeat(20))
+    );
+}
+
+#[test]
+fn no_elves_no_stats() {
+    assert_eq!(Stats::new(&[], 10), None);
+}
+
+#[test]
+fn one_total_fills_one_bin() {
+    let stats = Stats::new(&[vec![500], vec![200, 300]], 10).unwrap();
+    assert_eq!(stats.std_dev, 0.0);
+    assert_eq!(
+        stats.histogram,
+        vec![Bin {
+            start: 500,
+            end: 500,
+            count: 2
+        }]
+    );
+}
+
+#[test]
+fn extreme_totals() {
+    let items = [
+        vec![-9_000_000_000_000_000_000],
+        vec![9_000_000_000_000_000_000],
+    ];
+    let stats = Stats::new(&items, 10).unwrap();
+    assert_eq!(stats.median, 0.0);
+    assert_eq!(stats.histogram.len(), 10);
+    assert_eq!(stats.histogram[0].count, 1);
+    assert_eq!(stats.histogram[9].count, 1);
+    assert_eq!(stats.histogram[9].end, 9_000_000_000_000_000_000);
+
+    let stats = Stats::new(&[vec![i64::MIN], vec![i64::MAX]], 3).unwrap();
+    assert_eq!(stats.histogram.first().unwrap().start, i64::MIN);
+    assert_eq!(stats.histogram.last().unwrap().end, i64::MAX);
+    assert_eq!(
+        stats.histogram.iter().map(|bin| bin.count).sum::<usize>(),
+        2
+    );
+}
+
+/// Mostly everyday counts, with some near the ends of `i64`; four of either
+/// still fit in a total.
+fn calories() -> impl Strategy<Value = i64> {
+    prop_oneof![
+        4 => -1000i64..100_000,
+        1 => i64::MIN / 4..=i64::MIN / 4 + 1000,
+        1 => i64::MAX / 4 - 1000..=i64::MAX / 4,
+    ]
+}
+
+proptest! {
+    #[test]
+    fn bins_cover_every_elf(
+        items in prop::collection::vec(prop::collection::vec(calories(), 1..5), 1..40),
+        bins in 1usize..20,
+    ) {
+        let stats = Stats::new(&items, bins).unwrap();
+        prop_assert!(stats.histogram.len() <= bins);
+        prop_assert_eq!(stats.histogram.iter().map(|bin| bin.count).sum::<usize>(), items.len());
+        prop_assert_eq!(stats.histogram.first().unwrap().start, stats.min);
+        prop_assert_eq!(stats.histogram.last().unwrap().end, stats.max);
+        for pair in stats.histogram.windows(2) {
+            prop_assert_eq!(pair[0].end + 1, pair[1].start);
+        }
+        for pair in stats.percentiles.windows(2) {
+            prop_assert!(pair[0].1 <= pair[1].1);
+        }
+        prop_assert!(stats.min as f64 <= stats.median && stats.median <= stats.max as f64);
+    }
+}
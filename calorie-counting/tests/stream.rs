@@ -0,0 +1,116 @@
+use calorie_counting::stream::{self, Aggregator, ReadError};
+use calorie_counting::{self as day, Puzzle};
+use common::Solution;
+use proptest::prelude::*;
+
+const EXAMPLE: &str = include_str!("../fixtures/example/input.txt");
+
+#[test]
+fn matches_the_parsed_answers() {
+    let summary = stream::aggregate(EXAMPLE.as_bytes(), 3).unwrap();
+    assert_eq!(summary.elves, 5);
+    assert_eq!(summary.items, 10);
+    assert_eq!(summary.max(), 24000);
+    assert_eq!(summary.top_total(), 45000);
+    let items = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(summary.top, day::rank_elves(&items, 3));
+}
+
+#[test]
+fn blank_lines_only_separate() {
+    let input = "\n\n1000\n2000\n\n\n\n3000\r\n\n4000";
+    let summary = stream::aggregate(input.as_bytes(), 10).unwrap();
+    assert_eq!(summary.elves, 3);
+    assert_eq!(summary.items, 4);
+    let totals = summary
+        .top
+        .iter()
+        .map(|elf| (elf.elf, elf.total))
+        .collect::<Vec<(usize, i64)>>();
+    assert_eq!(totals, vec![(2, 4000), (0, 3000), (1, 3000)]);
+    assert_eq!(
+        Puzzle::parse(input).unwrap(),
+        vec![vec![1000, 2000], vec![3000], vec![4000]]
+    );
+}
+
+#[test]
+fn empty_input_has_no_elves() {
+    let summary = stream::aggregate("\n\n".as_bytes(), 3).unwrap();
+    assert_eq!(
+        (summary.elves, summary.max(), summary.top_total()),
+        (0, 0, 0)
+    );
+    assert_eq!(day::part1(&Puzzle::parse("").unwrap()), 0);
+}
+
+#[test]
+fn reports_the_line_parse_would() {
+    let input = "1000\n\n20x0\n";
+    let error = match stream::aggregate(input.as_bytes(), 3) {
+        Err(ReadError::Parse(e)) => e,
+        other => panic!("expected a parse error, got {:?}", other),
+    };
+    assert_eq!(error, Puzzle::parse(input).unwrap_err());
+}
+
+#[test]
+fn reads_csv_and_annotated_inventories() {
+    let expected = stream::aggregate(EXAMPLE.as_bytes(), 3).unwrap();
+    for input in [
+        include_str!("../fixtures/csv/input.txt"),
+        include_str!("../fixtures/annotated/input.txt"),
+    ] {
+        assert_eq!(stream::aggregate(input.as_bytes(), 3).unwrap(), expected);
+    }
+}
+
+#[test]
+fn points_at_the_line_in_other_formats() {
+    for input in [
+        "\n\nAnn,apple,1000\nBo,1000\n",
+        "\r\n[Ann]\n\napple: lots\n",
+    ] {
+        let error = match stream::aggregate(input.as_bytes(), 3) {
+            Err(ReadError::Parse(e)) => e,
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+        assert_eq!(error, Puzzle::parse(input).unwrap_err());
+    }
+}
+
+#[test]
+fn takes_lines_one_at_a_time() {
+    let mut aggregator = Aggregator::new(1);
+    for line in ["5", "", "7", "1"] {
+        aggregator.push_line(line).unwrap();
+    }
+    let summary = aggregator.finish();
+    assert_eq!((summary.elves, summary.max()), (2, 8));
+}
+
+proptest! {
+    #[test]
+    fn agrees_with_parse(
+        elves in prop::collection::vec(prop::collection::vec(0i64..100, 1..4), 0..30),
+        blanks in prop::collection::vec(1usize..4, 31),
+        k in 0usize..6,
+    ) {
+        // Any number of blank lines before, after and between elves.
+        let mut input = "\n".repeat(blanks[0] - 1);
+        for (i, elf) in elves.iter().enumerate() {
+            if i > 0 {
+                input.push_str(&"\n".repeat(blanks[i]));
+            }
+            for calories in elf {
+                input.push_str(&format!("{}\n", calories));
+            }
+        }
+        input.push_str(&"\n".repeat(blanks[30] - 1));
+        let summary = stream::aggregate(input.as_bytes(), k).unwrap();
+        let items = Puzzle::parse(&input).unwrap();
+        prop_assert_eq!(&items, &elves);
+        prop_assert_eq!(summary.elves, elves.len());
+        prop_assert_eq!(summary.top, day::rank_elves(&items, k));
+    }
+}
@@ -0,0 +1,16 @@
+//! Random section assignments for `size` pairs of elves.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let range = |rng: &mut Rng| {
+            let start = rng.range(1..=99);
+            format!("{}-{}", start, rng.range(start..=99))
+        };
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(rng), range(rng)))
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+use common::{parse_at, Answer, NoConfig, ParseError, Solution, SolveError};
+use interval_set::IntervalSet;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+mod generate;
+mod reference;
+
+lazy_static! {
+    static ref ASSIGNMENT_RE: Regex = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+}
+
+#[derive(Debug)]
+pub struct Assignment {
+    sections: Vec<IntervalSet>,
+}
+
+impl Assignment {
+    pub fn new(input: &str, l: &str) -> Result<Assignment, ParseError> {
+        let caps = ASSIGNMENT_RE
+            .captures(l)
+            .ok_or_else(|| ParseError::at(input, l, "expected an assignment like \"2-4,6-8\""))?;
+        let section = |i| parse_at::<i64>(input, caps.get(i).unwrap().as_str(), "a section id");
+        let mut sections = Vec::new();
+        for i in [1, 3] {
+            let (start, end) = (section(i)?, section(i + 1)?);
+            if start > end {
+                let range = &l[caps.get(i).unwrap().start()..caps.get(i + 1).unwrap().end()];
+                return Err(ParseError::at(input, range, "range ends before it starts"));
+            }
+            sections.push(IntervalSet::from([(start, end)]));
+        }
+        Ok(Assignment { sections })
+    }
+
+    pub fn num_fully_contained_ranges(&self) -> u64 {
+        self.count_pairs(|a, b| a.is_subset(b) || b.is_subset(a))
+    }
+
+    pub fn num_overlapping_ranges(&self) -> u64 {
+        self.count_pairs(|a, b| !a.intersection(b).is_empty())
+    }
+
+    fn count_pairs(&self, f: impl Fn(&IntervalSet, &IntervalSet) -> bool) -> u64 {
+        let mut counter = 0;
+        for i in 0..self.sections.len() {
+            for j in (i + 1)..self.sections.len() {
+                if f(&self.sections[i], &self.sections[j]) {
+                    counter += 1
+                }
+            }
+        }
+        counter
+    }
+}
+
+pub fn find_total_assignments_fully_contained_ranges(assignments: &[Assignment]) -> u64 {
+    assignments
+        .iter()
+        .map(|a| a.num_fully_contained_ranges())
+        .sum()
+}
+
+pub fn find_total_assignments_overlapping_ranges(assignments: &[Assignment]) -> u64 {
+    assignments.iter().map(|a| a.num_overlapping_ranges()).sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Assignment>, ParseError> {
+    input.lines().map(|l| Assignment::new(input, l)).collect()
+}
+
+pub fn part1(assignments: &[Assignment]) -> u64 {
+    find_total_assignments_fully_contained_ranges(assignments)
+}
+
+pub fn part2(assignments: &[Assignment]) -> u64 {
+    find_total_assignments_overlapping_ranges(assignments)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    const NAME: &'static str = "camp-cleanup";
+
+    type Input = Vec<Assignment>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(assignments: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(assignments).into())
+    }
+
+    fn part2(assignments: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(assignments).into())
+    }
+}
@@ -0,0 +1,41 @@
+//! Checks assignments section by section.
+
+use crate::{Assignment, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+/// Every section id in one elf's assignment.
+fn sections(assignment: &Assignment, elf: usize) -> Vec<i64> {
+    assignment.sections[elf]
+        .iter()
+        .flat_map(|(start, end)| start..=end)
+        .collect()
+}
+
+fn count(assignments: &[Assignment], f: impl Fn(&[i64], &[i64]) -> bool) -> u64 {
+    let mut counter = 0;
+    for assignment in assignments {
+        let (a, b) = (sections(assignment, 0), sections(assignment, 1));
+        if f(&a, &b) {
+            counter += 1;
+        }
+    }
+    counter
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(
+        assignments: &Self::Input,
+        _config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        let contains = |a: &[i64], b: &[i64]| b.iter().all(|s| a.contains(s));
+        Ok(count(assignments, |a, b| contains(a, b) || contains(b, a)).into())
+    }
+
+    fn reference_part2(
+        assignments: &Self::Input,
+        _config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        Ok(count(assignments, |a, b| a.iter().any(|s| b.contains(s))).into())
+    }
+}
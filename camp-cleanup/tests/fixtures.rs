@@ -0,0 +1,6 @@
+use camp_cleanup::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,17 @@
+use camp_cleanup::{self as day, Puzzle};
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..100) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let contained = day::part1(&input);
+        let overlapping = day::part2(&input);
+        prop_assert!(contained <= overlapping);
+        prop_assert!(overlapping <= size as u64);
+    }
+}
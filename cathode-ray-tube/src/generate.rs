@@ -0,0 +1,18 @@
+//! Random programs of `size` instructions.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                if rng.chance(0.4) {
+                    String::from("noop\n")
+                } else {
+                    format!("addx {}\n", rng.range(-15..=15))
+                }
+            })
+            .collect()
+    }
+}
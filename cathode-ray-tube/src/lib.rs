@@ -0,0 +1,214 @@
+use common::{parse_at, Answer, ParseError, Solution, SolveError};
+use grid::{Grid, Pos};
+use serde::Deserialize;
+use std::fmt;
+
+mod generate;
+mod reference;
+mod visualize;
+
+/// Puzzle constants, overridable through `common::config`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Cycles whose signal strength part 1 sums.
+    pub sample_cycles: Vec<i64>,
+    /// Cycles the CRT draws for part 2.
+    pub cycles: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            sample_cycles: vec![20, 60, 100, 140, 180, 220],
+            cycles: 240,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    NoOp,
+    AddX(i64),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct CPU {
+    pc: usize,
+    cooldown: usize,
+    x: i64,
+    program: Vec<Instruction>,
+}
+
+impl CPU {
+    pub fn new(program: Vec<Instruction>) -> CPU {
+        CPU {
+            pc: 0,
+            cooldown: 0,
+            x: 1,
+            program,
+        }
+    }
+
+    pub fn tick(&mut self) {
+        // Once the program has run out, the CPU idles with `x` unchanged
+        let ins = match self.program.get(self.pc) {
+            Some(ins) => ins,
+            None => return,
+        };
+        if self.cooldown == 0 {
+            match ins {
+                Instruction::NoOp => self.pc += 1,
+                Instruction::AddX(_) => {
+                    self.cooldown = 2;
+                }
+            }
+        } else if self.cooldown == 1 {
+            match ins {
+                Instruction::NoOp => {
+                    panic!("invalid state");
+                }
+                Instruction::AddX(v) => {
+                    self.x += v;
+                    self.pc += 1;
+                }
+            }
+        }
+        if self.cooldown > 0 {
+            self.cooldown -= 1;
+        }
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct CRT {
+    cpu: CPU,
+    position: Pos,
+    buffer: Grid<char>,
+}
+
+impl CRT {
+    pub fn new(cpu: CPU) -> CRT {
+        CRT {
+            cpu,
+            position: (0, 0),
+            buffer: Grid::new(6, 40, '.'),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        // Draw
+        let (_, c) = self.position;
+        if self.cpu.x == c as i64 || self.cpu.x - 1 == c as i64 || self.cpu.x + 1 == c as i64 {
+            self.buffer[self.position] = '#';
+        } else {
+            self.buffer[self.position] = '.';
+        }
+
+        // Update CRT position
+        let (mut r, mut c) = self.position;
+        c += 1;
+        if c >= self.buffer.width() {
+            c = 0;
+            r += 1;
+            if r >= self.buffer.height() {
+                r = 0;
+            }
+        }
+        self.position = (r, c);
+
+        // Update CPU
+        self.cpu.tick()
+    }
+}
+
+impl fmt::Display for CRT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.buffer)
+    }
+}
+
+/// Largest value, either way, the register may reach. Signal strengths
+/// multiply it by a cycle number, so it stays well short of `i64`.
+pub const REGISTER_LIMIT: i64 = 1 << 40;
+
+/// Rejects programs that would take the register beyond [`REGISTER_LIMIT`].
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut program: Vec<Instruction> = Vec::new();
+    let mut x: i64 = 1;
+    for l in input.lines() {
+        let args = l.split(" ").collect::<Vec<&str>>();
+        match args[..] {
+            ["noop"] => {
+                program.push(Instruction::NoOp);
+            }
+            ["addx", v] => {
+                let operand = v;
+                let v = parse_at::<i64>(input, operand, "an integer")?;
+                x = x
+                    .checked_add(v)
+                    .filter(|x| x.unsigned_abs() <= REGISTER_LIMIT as u64)
+                    .ok_or_else(|| ParseError::at(input, operand, "register out of range"))?;
+                program.push(Instruction::AddX(v));
+            }
+            ["noop" | "addx", ..] => {
+                return Err(ParseError::at(input, l, "wrong number of operands"));
+            }
+            _ => {
+                return Err(ParseError::at(input, args[0], "invalid operation"));
+            }
+        }
+    }
+    Ok(program)
+}
+
+pub fn find_total_interesting_signal_strength(cpu: &mut CPU, interesting_cycles: &[i64]) -> i64 {
+    let mut total_signal_strength = 0;
+    let last_cycle = interesting_cycles.iter().copied().max().unwrap_or(0);
+    for cycle in 1..=last_cycle {
+        if interesting_cycles.contains(&cycle) {
+            total_signal_strength += cycle * cpu.x;
+        }
+        cpu.tick();
+    }
+    total_signal_strength
+}
+
+pub fn part1(instructions: &[Instruction], config: &Config) -> i64 {
+    let mut cpu = CPU::new(instructions.to_vec());
+    find_total_interesting_signal_strength(&mut cpu, &config.sample_cycles)
+}
+
+pub fn part2(instructions: &[Instruction], config: &Config) -> CRT {
+    let cpu = CPU::new(instructions.to_vec());
+    let mut crt = CRT::new(cpu);
+    for _ in 0..config.cycles {
+        crt.tick();
+    }
+    crt
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 10;
+    const NAME: &'static str = "cathode-ray-tube";
+
+    type Input = Vec<Instruction>;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(instructions, config).into())
+    }
+
+    fn part2(instructions: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let crt = part2(instructions, config);
+        Ok(Answer::Image(
+            crt.to_string().lines().map(String::from).collect(),
+        ))
+    }
+}
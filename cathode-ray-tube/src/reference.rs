@@ -0,0 +1,69 @@
+//! Writes out the register's value during every cycle, then reads answers
+//! off that list.
+
+use crate::{Instruction, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+/// `x` during cycles `1..=cycles`, with index 0 left unused.
+fn register_values(instructions: &[Instruction], cycles: usize) -> Vec<i64> {
+    let mut x = 1;
+    let mut values = vec![x];
+    for ins in instructions {
+        match ins {
+            Instruction::NoOp => values.push(x),
+            Instruction::AddX(v) => {
+                values.push(x);
+                values.push(x);
+                x += v;
+            }
+        }
+    }
+    while values.len() <= cycles {
+        values.push(x);
+    }
+    values
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(
+        instructions: &Self::Input,
+        config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        let last = config
+            .sample_cycles
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(0);
+        let values = register_values(instructions, last as usize);
+        let total: i64 = config
+            .sample_cycles
+            .iter()
+            .filter(|&&cycle| cycle >= 1)
+            .map(|&cycle| cycle * values[cycle as usize])
+            .sum();
+        Ok(total.into())
+    }
+
+    fn reference_part2(
+        instructions: &Self::Input,
+        config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        let values = register_values(instructions, config.cycles);
+        let mut screen = vec![vec!['.'; 40]; 6];
+        for (cycle, &x) in values.iter().enumerate().take(config.cycles + 1).skip(1) {
+            let pixel = (cycle - 1) % 240;
+            let (row, column) = (pixel / 40, pixel % 40);
+            let lit = (x - column as i64).abs() <= 1;
+            screen[row][column] = if lit { '#' } else { '.' };
+        }
+        Ok(Answer::Image(
+            screen
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,23 @@
+//! Watch the CRT draw one pixel per cycle.
+
+use crate::{Config, Puzzle, CPU, CRT};
+use common::frames::{Frame, Record, Recording, Visualize};
+
+impl Record for CRT {
+    fn frame(&self) -> Frame {
+        Frame::from_text(&self.to_string())
+    }
+}
+
+/// Both parts run the same program, so both draw the part 2 picture.
+impl Visualize for Puzzle {
+    fn record(instructions: &Self::Input, config: &Config, _part: u8, recording: &mut Recording) {
+        let mut crt = CRT::new(CPU::new(instructions.to_vec()));
+        recording.step(&crt);
+        for _ in 0..config.cycles {
+            crt.tick();
+            recording.step(&crt);
+        }
+        recording.finish(&crt);
+    }
+}
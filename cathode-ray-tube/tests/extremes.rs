@@ -0,0 +1,25 @@
+use cathode_ray_tube::{Puzzle, REGISTER_LIMIT};
+use common::Solution;
+
+#[test]
+fn rejects_programs_that_overflow_the_register() {
+    let input = format!("addx {}\naddx {}\n", i64::MAX - 1, i64::MAX);
+    let e = Puzzle::parse(&input).unwrap_err();
+    assert_eq!((e.line, e.message.as_str()), (1, "register out of range"));
+
+    let input = format!("addx {}\nnoop\naddx 1\n", REGISTER_LIMIT - 1);
+    let e = Puzzle::parse(&input).unwrap_err();
+    assert_eq!((e.line, e.text.as_str()), (3, "1"));
+}
+
+#[test]
+fn solves_at_the_register_limit() {
+    let input = format!(
+        "addx {}\naddx {}\n",
+        REGISTER_LIMIT - 1,
+        -2 * REGISTER_LIMIT
+    );
+    let program = Puzzle::parse(&input).unwrap();
+    assert!(Puzzle::part1(&program, &Default::default()).is_ok());
+    assert!(Puzzle::part2(&program, &Default::default()).is_ok());
+}
@@ -0,0 +1,6 @@
+use cathode_ray_tube::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
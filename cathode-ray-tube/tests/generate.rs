@@ -0,0 +1,18 @@
+use cathode_ray_tube::{self as day, Config, Puzzle};
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..200) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let config = Config::default();
+        day::part1(&input, &config);
+        let image = day::part2(&input, &config).to_string();
+        prop_assert_eq!(image.lines().count(), 6);
+        prop_assert!(image.lines().all(|row| row.len() == 40));
+    }
+}
@@ -0,0 +1,16 @@
+use cathode_ray_tube::Puzzle;
+use common::frames::{Frame, Recording, Visualize};
+use common::Solution;
+use std::fs;
+
+#[test]
+fn records_each_cycle_up_to_the_picture() {
+    let input = fs::read_to_string("fixtures/example/input.txt").unwrap();
+    let picture = fs::read_to_string("fixtures/example/part2.txt").unwrap();
+    let instructions = Puzzle::parse(&input).unwrap();
+    let mut recording = Recording::new(1000);
+    Puzzle::record(&instructions, &Default::default(), 2, &mut recording);
+    let frames = recording.frames();
+    assert_eq!(frames.len(), 241);
+    assert_eq!(frames.last(), Some(&Frame::from_text(&picture)));
+}
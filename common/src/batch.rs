@@ -0,0 +1,179 @@
+//! Solving one day over many input files.
+//!
+//! Every file gets a [`Row`] whether or not it solves, so one bad input in a
+//! directory of dozens doesn't hide the rest.
+
+use crate::cli::Report;
+use crate::Answer;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// The outcome of one input file.
+#[derive(Debug, Clone, Serialize)]
+pub struct Row {
+    pub file: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    #[serde(rename = "time_ms", serialize_with = "serialize_millis")]
+    pub time: Duration,
+    pub errors: Vec<String>,
+}
+
+fn serialize_millis<S: serde::Serializer>(time: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_f64(time.as_micros() as f64 / 1000.0)
+}
+
+impl Row {
+    pub fn from_report(file: String, report: &Report, time: Duration) -> Self {
+        let mut row = Row {
+            file,
+            part1: None,
+            part2: None,
+            time,
+            errors: Vec::new(),
+        };
+        for part in &report.parts {
+            match &part.answer {
+                Ok(answer) if part.part == 1 => row.part1 = Some(answer.clone()),
+                Ok(answer) => row.part2 = Some(answer.clone()),
+                Err(e) => row.errors.push(format!("part {}: {}", part.part, e)),
+            }
+        }
+        row
+    }
+
+    /// A file that couldn't be read or parsed.
+    pub fn failed(file: String, error: String, time: Duration) -> Self {
+        Row {
+            file,
+            part1: None,
+            part2: None,
+            time,
+            errors: vec![error],
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// The input files `pattern` names: every file in a directory, the files a
+/// glob such as `inputs/*.txt` matches, or a single file. Sorted by path.
+pub fn expand(pattern: &str) -> Result<Vec<PathBuf>, String> {
+    let path = PathBuf::from(pattern);
+    let mut paths = if path.is_dir() {
+        fs::read_dir(&path)
+            .map_err(|e| format!("unable to read {}: {}", pattern, e))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect::<Vec<PathBuf>>()
+    } else if path.is_file() {
+        vec![path]
+    } else {
+        glob::glob(pattern)
+            .map_err(|e| format!("invalid pattern {}: {}", pattern, e))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect()
+    };
+    if paths.is_empty() {
+        return Err(format!("no input files match {}", pattern));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Reads and solves each file in turn, timing the whole of each.
+pub fn run(paths: &[PathBuf], mut solve: impl FnMut(&str) -> Result<Report, String>) -> Vec<Row> {
+    paths
+        .iter()
+        .map(|path| {
+            let file = path.display().to_string();
+            let start = Instant::now();
+            let outcome = fs::read_to_string(path)
+                .map_err(|e| format!("unable to read input: {}", e))
+                .and_then(|input| solve(&input));
+            match outcome {
+                Ok(report) => Row::from_report(file, &report, start.elapsed()),
+                Err(e) => Row::failed(file, e, start.elapsed()),
+            }
+        })
+        .collect()
+}
+
+const HEADER: [&str; 5] = ["file", "part1", "part2", "time_ms", "errors"];
+
+fn cells(row: &Row, image: impl Fn(&[String]) -> String) -> [String; 5] {
+    let answer = |answer: &Option<Answer>| match answer {
+        Some(Answer::Image(rows)) => image(rows),
+        Some(answer) => answer.to_string(),
+        None => String::new(),
+    };
+    [
+        row.file.clone(),
+        answer(&row.part1),
+        answer(&row.part2),
+        format!("{:.3}", row.time.as_micros() as f64 / 1000.0),
+        row.errors.join("; "),
+    ]
+}
+
+/// Lines up the rows under a header. Image answers don't fit a table cell, so
+/// they are summarized; CSV and JSON keep them whole.
+pub fn to_table(rows: &[Row]) -> String {
+    let cells = rows
+        .iter()
+        .map(|row| cells(row, |image| format!("<{}-row image>", image.len())))
+        .collect::<Vec<[String; 5]>>();
+    let mut widths = HEADER.map(str::len);
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    let header = HEADER.map(String::from);
+    for row in std::iter::once(&header).chain(&cells) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// Quotes a CSV field when it holds a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One CSV record per file after a header. Image rows are joined by newlines
+/// within a quoted field.
+pub fn to_csv(rows: &[Row]) -> String {
+    let mut csv = HEADER.join(",");
+    csv.push('\n');
+    for row in rows {
+        let record = cells(row, |image| image.join("\n")).map(|cell| csv_field(&cell));
+        csv.push_str(&record.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// One JSON object per line, per file.
+pub fn to_json(rows: &[Row]) -> String {
+    rows.iter()
+        .map(|row| serde_json::to_string(row).unwrap() + "\n")
+        .collect()
+}
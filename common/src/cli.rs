@@ -0,0 +1,428 @@
+//! Shared command-line front end for the per-day binaries and the `aoc` runner.
+
+use crate::batch::{self, Row};
+use crate::config::Overrides;
+use crate::progress::{Progress, Update};
+use crate::{read_stdin, trace, Answer, ParseError, Solution, SolveError};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::info_span;
+
+/// How solved answers are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format: {}", s)),
+        }
+    }
+}
+
+/// The outcome of solving one part.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, SolveError>,
+    pub time: Duration,
+}
+
+/// The outcome of parsing an input and solving some of its parts.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub name: &'static str,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    day: u8,
+    name: &'a str,
+    #[serde(flatten)]
+    answers: BTreeMap<String, Option<&'a Answer>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    errors: BTreeMap<String, String>,
+    timings: BTreeMap<String, f64>,
+}
+
+#[derive(Serialize)]
+struct JsonParseError<'a> {
+    day: u8,
+    name: &'a str,
+    error: &'a ParseError,
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_micros() as f64 / 1000.0
+}
+
+/// Parses `input` and solves the requested `parts`, timing each phase.
+pub fn solve<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    config: &S::Config,
+) -> Result<Report, ParseError> {
+    solve_with::<S>(input, parts, config, &Progress::new())
+}
+
+/// Like [`solve`], reporting to `progress`. Once it cancels, parts not yet
+/// started are cancelled too.
+///
+/// Parsing and each part run in `parse`, `part1` and `part2` spans inside a
+/// `solve` span naming the day.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    config: &S::Config,
+    progress: &Progress,
+) -> Result<Report, ParseError> {
+    let _solve = info_span!("solve", day = S::DAY, name = S::NAME).entered();
+    let start = Instant::now();
+    let parsed = info_span!("parse", bytes = input.len()).in_scope(|| S::parse(input))?;
+    let parse_time = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                _ if progress.is_cancelled() => {
+                    Err(SolveError::Cancelled(String::from("not started")))
+                }
+                1 => info_span!("part1").in_scope(|| S::part1_with(&parsed, config, progress)),
+                _ => info_span!("part2").in_scope(|| S::part2_with(&parsed, config, progress)),
+            };
+            PartReport {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(Report {
+        day: S::DAY,
+        name: S::NAME,
+        parse_time,
+        parts,
+    })
+}
+
+impl Report {
+    pub fn is_solved(&self) -> bool {
+        self.parts.iter().all(|part| part.answer.is_ok())
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part.answer, Err(SolveError::Cancelled(_))))
+    }
+
+    /// Parsing plus every part.
+    pub fn time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    /// Renders the report as a single JSON object, e.g.
+    /// `{"day":1,"name":"calorie-counting","part1":24000,"part2":45000,"timings":{..}}`.
+    ///
+    /// Timings are in milliseconds; a part without an answer is `null` and its
+    /// reason is listed under `errors`.
+    pub fn to_json(&self) -> String {
+        let mut answers = BTreeMap::new();
+        let mut errors = BTreeMap::new();
+        let mut timings = BTreeMap::new();
+        timings.insert(String::from("parse_ms"), millis(self.parse_time));
+        for part in &self.parts {
+            let key = format!("part{}", part.part);
+            answers.insert(key.clone(), part.answer.as_ref().ok());
+            if let Err(e) = &part.answer {
+                errors.insert(key.clone(), e.to_string());
+            }
+            timings.insert(format!("{}_ms", key), millis(part.time));
+        }
+        serde_json::to_string(&JsonReport {
+            day: self.day,
+            name: self.name,
+            answers,
+            errors,
+            timings,
+        })
+        .unwrap()
+    }
+
+    /// Writes each part's answer to stdout after the matching label, and any
+    /// unsolved part to stderr.
+    pub fn print(&self, format: Format, labels: &[&str]) {
+        match format {
+            Format::Json => println!("{}", self.to_json()),
+            Format::Csv => {
+                let row = Row::from_report(String::from("-"), self, self.time());
+                print!("{}", batch::to_csv(&[row]));
+            }
+            Format::Text => {
+                for (part, label) in self.parts.iter().zip(labels) {
+                    match &part.answer {
+                        Ok(answer @ Answer::Image(_)) => print!("{}:\n{}", label, answer),
+                        Ok(answer) => println!("{}: {}", label, answer),
+                        Err(e) => eprintln!("{}: {}", label, e),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Reports an input that could not be parsed; JSON goes to stdout so
+/// consumers always get one object per run.
+pub fn print_parse_error(day: u8, name: &str, error: &ParseError, format: Format) {
+    match format {
+        Format::Json => {
+            let json = JsonParseError { day, name, error };
+            println!("{}", serde_json::to_string(&json).unwrap());
+        }
+        Format::Csv => {
+            let row = Row::failed(String::from("-"), error.to_string(), Duration::ZERO);
+            print!("{}", batch::to_csv(&[row]));
+        }
+        Format::Text => eprintln!("error: {}", error),
+    }
+}
+
+/// Handles a config flag shared by every front end, returning whether `arg`
+/// was one. `--config FILE` loads a TOML file and `--set key=value` sets one
+/// key; both may repeat, later ones winning.
+pub fn parse_config_arg<I: Iterator<Item = String>>(
+    arg: &str,
+    args: &mut I,
+    overrides: &mut Overrides,
+) -> Result<bool, String> {
+    match arg {
+        "--config" | "-c" => {
+            let path = args.next().ok_or("missing value for --config")?;
+            overrides.merge(Overrides::load(path)?);
+        }
+        "--set" | "-s" => {
+            overrides.set(&args.next().ok_or("missing value for --set")?)?;
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Finishes any trace `--trace` started, then exits with `code`. Use this
+/// rather than `process::exit` once a trace may be running.
+pub fn exit(code: i32) -> ! {
+    trace::finish();
+    process::exit(code);
+}
+
+/// Starts the trace `--trace` asked for, exiting if its file can't be made.
+pub fn start_trace(path: &Option<String>) {
+    if let Some(path) = path {
+        if let Err(e) = trace::start(path) {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Parses a `--timeout` value, in seconds.
+pub fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|secs| *secs > 0.0)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("--timeout must be a positive number of seconds: {}", value))
+}
+
+/// A fresh [`Progress`] that cancels after `timeout`, if given.
+pub fn progress(timeout: Option<Duration>) -> Progress {
+    match timeout {
+        Some(timeout) => Progress::new().timeout(timeout),
+        None => Progress::new(),
+    }
+}
+
+const BAR_WIDTH: usize = 30;
+
+/// Adds a progress bar on stderr, redrawn at most ten times a second, when
+/// stderr is a terminal. Call [`clear_progress_bar`] before printing.
+pub fn with_progress_bar(progress: Progress) -> Progress {
+    if !io::stderr().is_terminal() {
+        return progress;
+    }
+    let last_drawn = Mutex::new(None::<Instant>);
+    progress.on_update(move |update: Update| {
+        let mut last_drawn = last_drawn.lock().unwrap();
+        let due = last_drawn.is_none_or(|last| last.elapsed() >= Duration::from_millis(100));
+        if due || update.done >= update.total {
+            let filled = (update.fraction() * BAR_WIDTH as f64) as usize;
+            eprint!(
+                "\r[{}{}] {:>3.0}% {}/{}",
+                "#".repeat(filled),
+                " ".repeat(BAR_WIDTH - filled),
+                update.fraction() * 100.0,
+                update.done,
+                update.total
+            );
+            *last_drawn = Some(Instant::now());
+        }
+        true
+    })
+}
+
+/// Erases a bar [`with_progress_bar`] drew.
+pub fn clear_progress_bar() {
+    if io::stderr().is_terminal() {
+        eprint!("\r\x1b[K");
+    }
+}
+
+struct Args {
+    format: Format,
+    overrides: Overrides,
+    batch: Vec<String>,
+    timeout: Option<Duration>,
+    trace: Option<String>,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut format = Format::Text;
+    let mut overrides = Overrides::new();
+    let mut batch = Vec::new();
+    let mut timeout = None;
+    let mut trace = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                format = args.next().ok_or("missing value for --format")?.parse()?;
+            }
+            "--batch" | "-b" => {
+                batch.push(args.next().ok_or("missing value for --batch")?);
+            }
+            "--timeout" | "-t" => {
+                timeout = Some(parse_timeout(
+                    &args.next().ok_or("missing value for --timeout")?,
+                )?);
+            }
+            "--trace" => {
+                trace = Some(args.next().ok_or("missing value for --trace")?);
+            }
+            _ => {
+                if !parse_config_arg(&arg, &mut args, &mut overrides)? {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+            }
+        }
+    }
+    Ok(Args {
+        format,
+        overrides,
+        batch,
+        timeout,
+        trace,
+    })
+}
+
+/// Expands every `--batch` pattern, in order.
+pub fn expand_batch(patterns: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut paths = Vec::new();
+    for pattern in patterns {
+        paths.extend(batch::expand(pattern)?);
+    }
+    Ok(paths)
+}
+
+/// Prints batch results in `format` (text is a table) and exits non-zero if
+/// any file failed.
+pub fn finish_batch(rows: &[Row], format: Format) {
+    match format {
+        Format::Text => print!("{}", batch::to_table(rows)),
+        Format::Json => print!("{}", batch::to_json(rows)),
+        Format::Csv => print!("{}", batch::to_csv(rows)),
+    }
+    if !rows.iter().all(Row::is_ok) {
+        exit(1);
+    }
+}
+
+/// Entry point for a day's binary: reads the puzzle from stdin, solves both
+/// parts and prints them after `labels`, exiting non-zero on any failure.
+///
+/// With `--batch DIR|GLOB` it solves every matching file instead and prints a
+/// row per file, carrying on past failures. `--timeout SECS` cancels a slow
+/// solver, which then reports what it found so far; in a batch each file gets
+/// its own timeout. `--trace FILE` writes a Chrome trace of the run.
+pub fn run<S: Solution>(labels: [&str; 2]) {
+    run_with::<S>(|_| labels.map(String::from))
+}
+
+/// Like [`run`], with labels built from the resolved config, for days whose
+/// answers depend on a constant.
+pub fn run_with<S: Solution>(labels: impl FnOnce(&S::Config) -> [String; 2]) {
+    let usage = |e: String| -> ! {
+        eprintln!(
+            "{}\nusage: {} [--format text|json|csv] [--config FILE] [--set KEY=VALUE]... [--batch DIR|GLOB]... [--timeout SECS] [--trace FILE] < input",
+            e,
+            S::NAME
+        );
+        exit(2);
+    };
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| usage(e));
+    let format = args.format;
+    let config = args
+        .overrides
+        .resolve::<S::Config>()
+        .unwrap_or_else(|e| usage(e));
+    let labels = labels(&config);
+    start_trace(&args.trace);
+    if !args.batch.is_empty() {
+        let paths = expand_batch(&args.batch).unwrap_or_else(|e| usage(e));
+        let rows = batch::run(&paths, |input| {
+            solve_with::<S>(input, &[1, 2], &config, &progress(args.timeout))
+                .map_err(|e| e.to_string())
+        });
+        finish_batch(&rows, format);
+        exit(0);
+    }
+    let input = read_stdin();
+    let progress = with_progress_bar(progress(args.timeout));
+    let solved = solve_with::<S>(&input, &[1, 2], &config, &progress);
+    clear_progress_bar();
+    match solved {
+        Ok(report) => {
+            report.print(format, &[labels[0].as_str(), labels[1].as_str()]);
+            if let (true, Some(timeout)) = (report.is_cancelled(), args.timeout) {
+                eprintln!("timed out after {:?}", timeout);
+            }
+            if !report.is_solved() {
+                exit(1);
+            }
+        }
+        Err(e) => {
+            print_parse_error(S::DAY, S::NAME, &e, format);
+            exit(1);
+        }
+    }
+    exit(0);
+}
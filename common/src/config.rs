@@ -0,0 +1,83 @@
+//! Overrides for the constants baked into each puzzle.
+//!
+//! A day's [`Solution::Config`](crate::Solution::Config) holds its puzzle
+//! constants with the real puzzle's values as defaults. Overrides come from a
+//! TOML file and `key=value` assignments, later ones winning, e.g.
+//!
+//! ```toml
+//! row = 10
+//! max_coordinate = 20
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Config for a day with no tunable constants; rejects every key.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoConfig {}
+
+/// Config keys collected from files and flags, applied over a day's defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overrides {
+    table: Table,
+}
+
+impl Overrides {
+    pub fn new() -> Self {
+        Overrides::default()
+    }
+
+    /// Parses a TOML config document.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let table = s.parse::<Table>().map_err(|e| e.message().to_string())?;
+        Ok(Overrides { table })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|s| Self::parse(&s))
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Layers `other` on top, replacing any keys both define.
+    pub fn merge(&mut self, other: Overrides) {
+        self.table.extend(other.table);
+    }
+
+    /// Applies a `key=value` assignment. The value is read as TOML, falling
+    /// back to a plain string so `start=BB` works without quotes.
+    pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value: {}", assignment))?;
+        let (key, value) = (key.trim(), value.trim());
+        if key.is_empty() {
+            return Err(format!("missing key: {}", assignment));
+        }
+        let value = format!("v = {}", value)
+            .parse::<Table>()
+            .ok()
+            .and_then(|mut t| t.remove("v"))
+            .unwrap_or_else(|| Value::String(String::from(value)));
+        self.table.insert(String::from(key), value);
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Builds a day's config, keeping its defaults for keys not overridden.
+    pub fn resolve<C: DeserializeOwned>(&self) -> Result<C, String> {
+        self.table
+            .clone()
+            .try_into()
+            .map_err(|e: toml::de::Error| format!("invalid config: {}", e.message()))
+    }
+}
@@ -0,0 +1,95 @@
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Raised when puzzle input does not match the expected format.
+///
+/// `line` and `column` are 1-based and point at the start of `text`, the
+/// offending piece of input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `token`, which must be a slice of `input`.
+    pub fn at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+        let (line, column) = position(input, offset);
+        ParseError {
+            line,
+            column,
+            text: String::from(token),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error just past the end of `input`, for input that stops early.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset;
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Parses `token`, a slice of `input`, reporting `expected` at its position
+/// when it is not a valid `T`.
+pub fn parse_at<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(input, token, format!("expected {}", expected)))
+}
+
+/// Raised when well-formed input has no answer, or the solver was stopped
+/// before finding it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    NoSolution(String),
+    /// Cancelled through a [`Progress`](crate::Progress); the reason says how
+    /// far the solver got and what it had found.
+    Cancelled(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            SolveError::Cancelled(partial) => write!(f, "cancelled: {}", partial),
+        }
+    }
+}
+
+impl Error for SolveError {}
@@ -0,0 +1,136 @@
+//! Regression fixtures: sample inputs stored next to each crate with the answers
+//! they are known to produce.
+//!
+//! A crate keeps one directory per case under `fixtures/`, each holding an
+//! `input.txt` and the expected `part1.txt` and/or `part2.txt`. A missing part
+//! file means that part is not checked for the case. An optional `config.toml`
+//! overrides puzzle constants, as the example inputs often need.
+
+use crate::config::Overrides;
+use crate::{Answer, Solution};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// One fixture case and the answers it should produce.
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub name: String,
+    pub dir: PathBuf,
+    pub input: String,
+    pub config: Overrides,
+    pub expected: [Option<String>; 2],
+}
+
+fn read_optional(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(Some(s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Loads every case under `fixtures_dir`, sorted by name. A missing directory
+/// has no cases.
+pub fn load(fixtures_dir: &Path) -> io::Result<Vec<Fixture>> {
+    let entries = match fs::read_dir(fixtures_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let dir = entry?.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        let input = match read_optional(&dir.join("input.txt"))? {
+            Some(input) => input,
+            None => continue,
+        };
+        let config = match read_optional(&dir.join("config.toml"))? {
+            Some(config) => Overrides::parse(&config).map_err(|e| {
+                let path = dir.join("config.toml");
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            })?,
+            None => Overrides::new(),
+        };
+        fixtures.push(Fixture {
+            name: dir.file_name().unwrap().to_string_lossy().into_owned(),
+            expected: [
+                read_optional(&dir.join("part1.txt"))?,
+                read_optional(&dir.join("part2.txt"))?,
+            ],
+            input,
+            config,
+            dir,
+        });
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+/// Answers are compared as rendered text, ignoring trailing whitespace.
+fn matches(answer: &Answer, expected: &str) -> bool {
+    answer.to_string().trim_end() == expected.trim_end()
+}
+
+/// Solves `fixture` and describes every way the answers differ from the
+/// expected ones.
+pub fn check<S: Solution>(fixture: &Fixture) -> Vec<String> {
+    let config = match fixture.config.resolve::<S::Config>() {
+        Ok(config) => config,
+        Err(e) => return vec![e],
+    };
+    let parsed = match S::parse(&fixture.input) {
+        Ok(parsed) => parsed,
+        Err(e) => return vec![format!("unable to parse input: {}", e)],
+    };
+    let mut failures = Vec::new();
+    for (idx, expected) in fixture.expected.iter().enumerate() {
+        let expected = match expected {
+            Some(expected) => expected,
+            None => continue,
+        };
+        let part = idx + 1;
+        let answer = match part {
+            1 => S::part1(&parsed, &config),
+            _ => S::part2(&parsed, &config),
+        };
+        match answer {
+            Ok(answer) if matches(&answer, expected) => {}
+            Ok(answer) => failures.push(format!(
+                "part {}: expected {:?}, got {:?}",
+                part,
+                expected.trim_end(),
+                answer.to_string().trim_end()
+            )),
+            Err(e) => failures.push(format!("part {}: {}", part, e)),
+        }
+    }
+    failures
+}
+
+/// Checks every case under `fixtures_dir`, panicking with all failures so a
+/// crate's test reports them together.
+pub fn assert_all<S: Solution>(fixtures_dir: impl AsRef<Path>) {
+    let fixtures_dir = fixtures_dir.as_ref();
+    let fixtures = load(fixtures_dir).unwrap();
+    assert!(
+        !fixtures.is_empty(),
+        "no fixtures in {}",
+        fixtures_dir.display()
+    );
+    let failures = fixtures
+        .iter()
+        .flat_map(|fixture| {
+            check::<S>(fixture)
+                .into_iter()
+                .map(move |failure| format!("{}: {}", fixture.name, failure))
+        })
+        .collect::<Vec<String>>();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,130 @@
+//! Step-by-step pictures of the simulation puzzles.
+//!
+//! A simulation implements [`Record`] to draw its current state, and its day
+//! implements [`Visualize`] to solve while handing each step to a
+//! [`Recording`]. The `visualize` crate turns the frames into text dumps,
+//! terminal playback or animated images.
+
+use crate::Solution;
+use std::fmt;
+
+/// One picture of a simulation, a string of characters per row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub rows: Vec<String>,
+}
+
+impl Frame {
+    /// Splits drawn text, such as a `Display` implementation's output, into rows.
+    pub fn from_text(text: &str) -> Self {
+        Frame {
+            rows: text.lines().map(String::from).collect(),
+        }
+    }
+
+    /// The longest row, in characters.
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// A simulation that can draw its current state.
+pub trait Record {
+    fn frame(&self) -> Frame;
+}
+
+/// Frames kept while a simulation runs.
+///
+/// Long simulations are thinned rather than truncated: once more than
+/// `max_frames` are kept, every other one is dropped and only every second
+/// step is drawn from then on. The first and last states are always kept.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    max_frames: usize,
+    every: usize,
+    steps: usize,
+    last_kept: bool,
+    frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn new(max_frames: usize) -> Self {
+        Recording {
+            max_frames: max_frames.max(2),
+            every: 1,
+            steps: 0,
+            last_kept: false,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Draws only every `every`th step, before any thinning.
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    /// Called with the initial state and after each step; draws `sim` only
+    /// when the step is kept.
+    pub fn step(&mut self, sim: &impl Record) {
+        self.last_kept = self.steps.is_multiple_of(self.every);
+        if self.last_kept {
+            self.frames.push(sim.frame());
+            if self.frames.len() > self.max_frames {
+                let mut index = 0;
+                self.frames.retain(|_| {
+                    index += 1;
+                    index % 2 == 1
+                });
+                self.every *= 2;
+                self.last_kept = self.steps.is_multiple_of(self.every);
+            }
+        }
+        self.steps += 1;
+    }
+
+    /// Draws the final state if its step wasn't kept.
+    pub fn finish(&mut self, sim: &impl Record) {
+        if !self.last_kept {
+            self.frames.push(sim.frame());
+            self.last_kept = true;
+        }
+    }
+
+    /// Steps seen so far, including the initial state.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+/// A day whose simulation can be watched.
+pub trait Visualize: Solution {
+    /// Runs the simulation behind `part`, handing `recording` the initial state
+    /// and each step, then finishing it.
+    fn record(input: &Self::Input, config: &Self::Config, part: u8, recording: &mut Recording);
+}
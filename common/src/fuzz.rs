@@ -0,0 +1,28 @@
+//! The body every fuzz target shares.
+//!
+//! A target hands its bytes to [`solve`], which parses them and, when they
+//! parse, runs both parts. Any input that parses must solve without
+//! panicking, so parsers reject values large enough to overflow a solver.
+
+use crate::reference::Reference;
+use crate::Progress;
+use std::time::Duration;
+
+/// How long each part may run before it is cancelled. Solvers that never
+/// check their [`Progress`] are left to the fuzzer's own timeout.
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Parses `data` as `S`'s input and solves both parts under the
+/// [reference config](Reference::reference_config), whose search areas stay
+/// small. Errors are fine; only panics are bugs.
+pub fn solve<S: Reference>(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(input) = S::parse(text) else {
+        return;
+    };
+    let config = S::reference_config();
+    let _ = S::part1_with(&input, &config, &Progress::new().timeout(TIMEOUT));
+    let _ = S::part2_with(&input, &config, &Progress::new().timeout(TIMEOUT));
+}
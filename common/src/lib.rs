@@ -0,0 +1,79 @@
+use serde::de::DeserializeOwned;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::process;
+
+mod answer;
+pub mod batch;
+pub mod cli;
+pub mod config;
+mod error;
+pub mod fixtures;
+pub mod frames;
+pub mod fuzz;
+pub mod generate;
+pub mod progress;
+pub mod reference;
+pub mod trace;
+
+pub use answer::Answer;
+pub use config::NoConfig;
+pub use error::{parse_at, ParseError, SolveError};
+pub use progress::Progress;
+
+/// A day's puzzle: parse the input once, then solve either part from it.
+pub trait Solution {
+    const DAY: u8;
+    const NAME: &'static str;
+
+    type Input;
+    /// Puzzle constants that example inputs change, such as a row to scan.
+    type Config: Default + DeserializeOwned;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError>;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError>;
+
+    /// Like `part1`, reporting to `progress` and stopping when it cancels.
+    /// Only solvers slow enough to need it override this.
+    fn part1_with(
+        input: &Self::Input,
+        config: &Self::Config,
+        _progress: &Progress,
+    ) -> Result<Answer, SolveError> {
+        Self::part1(input, config)
+    }
+
+    /// Like `part2`, reporting to `progress` and stopping when it cancels.
+    fn part2_with(
+        input: &Self::Input,
+        config: &Self::Config,
+        _progress: &Progress,
+    ) -> Result<Answer, SolveError> {
+        Self::part2(input, config)
+    }
+}
+
+/// Reads all of stdin, exiting with a diagnostic if it cannot be read.
+pub fn read_stdin() -> String {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).or_exit();
+    input
+}
+
+/// Ends the process with a readable diagnostic instead of a panic backtrace.
+pub trait OrExit<T> {
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: Display> OrExit<T> for Result<T, E> {
+    fn or_exit(self) -> T {
+        match self {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
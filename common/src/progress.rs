@@ -0,0 +1,87 @@
+//! Progress reports and cancellation for solvers that run for minutes.
+//!
+//! A long solver takes a [`Progress`], reports how far it has got and checks
+//! [`Progress::is_cancelled`] often enough to stop promptly, answering
+//! [`SolveError::Cancelled`](crate::SolveError::Cancelled) with whatever it
+//! found so far. Nothing here needs threads or a terminal, so the wasm
+//! components share the same hooks.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How much of a solver's work is done, in whatever units it counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Update {
+    pub done: u64,
+    pub total: u64,
+}
+
+impl Update {
+    /// Between 0 and 1; an empty job counts as finished.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done.min(self.total) as f64 / self.total as f64
+        }
+    }
+}
+
+type Callback = dyn Fn(Update) -> bool + Send + Sync;
+
+/// Where a solver reports and learns whether to stop. Clones share the same
+/// cancellation, so a clone can be handed to another thread to cancel from.
+#[derive(Clone, Default)]
+pub struct Progress {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+    on_update: Option<Arc<Callback>>,
+}
+
+impl Progress {
+    /// Reports nowhere and never cancels on its own.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hands each update to `f`; returning `false` cancels.
+    pub fn on_update(mut self, f: impl Fn(Update) -> bool + Send + Sync + 'static) -> Self {
+        self.on_update = Some(Arc::new(f));
+        self
+    }
+
+    /// Cancels once `timeout` has passed, counting from now.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the solver should stop: cancelled, or past the deadline.
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            self.cancel();
+            return true;
+        }
+        false
+    }
+
+    /// Reports `done` of `total` units of work.
+    pub fn report(&self, done: u64, total: u64) {
+        if let Some(f) = &self.on_update {
+            if !f(Update { done, total }) {
+                self.cancel();
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Naive reference solvers for differential testing.
+//!
+//! A reference trades speed for being obviously correct: it scans every cell,
+//! walks every grain of sand or tries every order of valves. [`compare`] runs it
+//! against the day's real solver and reports where they disagree, which makes
+//! refactoring the fast paths much less nerve-wracking. Only small inputs are
+//! practical; [`differential`] draws them from the day's generator.
+
+use crate::generate::{generate, Generate};
+use crate::{Answer, ParseError, Solution, SolveError};
+use std::fmt;
+
+/// A day with a slow but straightforward second solver.
+pub trait Reference: Solution {
+    fn reference_part1(input: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError>;
+    fn reference_part2(input: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError>;
+
+    /// Constants small enough for the reference to finish quickly, such as a
+    /// shrunken search area.
+    fn reference_config() -> Self::Config {
+        Self::Config::default()
+    }
+}
+
+/// A part where the reference and the real solver disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u8,
+    pub reference: Result<Answer, SolveError>,
+    pub solver: Result<Answer, SolveError>,
+}
+
+fn describe(result: &Result<Answer, SolveError>) -> String {
+    match result {
+        Ok(Answer::Image(rows)) => format!("\n{}", rows.join("\n")),
+        Ok(answer) => answer.to_string(),
+        Err(e) => e.to_string(),
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "part {}: reference gave {}, solver gave {}",
+            self.part,
+            describe(&self.reference),
+            describe(&self.solver)
+        )
+    }
+}
+
+/// Runs both parts through both solvers. Two errors count as agreement even
+/// when their reasons are worded differently.
+pub fn compare<S: Reference>(input: &S::Input, config: &S::Config) -> Vec<Mismatch> {
+    let parts = [
+        (
+            1,
+            S::reference_part1(input, config),
+            S::part1(input, config),
+        ),
+        (
+            2,
+            S::reference_part2(input, config),
+            S::part2(input, config),
+        ),
+    ];
+    parts
+        .into_iter()
+        .filter(|(_, reference, solver)| match (reference, solver) {
+            (Ok(a), Ok(b)) => a != b,
+            (Err(_), Err(_)) => false,
+            _ => true,
+        })
+        .map(|(part, reference, solver)| Mismatch {
+            part,
+            reference,
+            solver,
+        })
+        .collect()
+}
+
+/// Compares the solvers on the input `S` generates for `seed` and `size`
+/// under [`Reference::reference_config`].
+pub fn differential<S: Generate + Reference>(
+    seed: u64,
+    size: usize,
+) -> Result<Vec<Mismatch>, ParseError> {
+    let config = S::reference_config();
+    let input = S::parse(&generate::<S>(seed, size, &config))?;
+    Ok(compare::<S>(&input, &config))
+}
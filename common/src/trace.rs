@@ -0,0 +1,35 @@
+//! Chrome trace files of where the time goes.
+//!
+//! Every day's parse and parts run in spans named `parse`, `part1` and
+//! `part2`, and the heaviest inner routines open their own. [`start`] records
+//! them to a file that `chrome://tracing` or Perfetto opens.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::sync::Mutex;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::prelude::*;
+
+/// Kept until [`finish`], since the trace is only complete once it drops.
+static GUARD: Mutex<Option<FlushGuard>> = Mutex::new(None);
+
+/// Records every span from now on to `path` as Chrome trace JSON. Call
+/// [`finish`] before exiting.
+pub fn start(path: &str) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("unable to create {}: {}", path, e))?;
+    let (layer, guard) = ChromeLayerBuilder::new()
+        .writer(BufWriter::new(file))
+        .include_args(true)
+        .build();
+    tracing_subscriber::registry()
+        .with(layer)
+        .try_init()
+        .map_err(|e| format!("unable to start tracing: {}", e))?;
+    *GUARD.lock().unwrap() = Some(guard);
+    Ok(())
+}
+
+/// Writes out the trace, if one was started.
+pub fn finish() {
+    drop(GUARD.lock().unwrap().take());
+}
@@ -0,0 +1,127 @@
+use common::batch::{self, Row};
+use common::{cli, parse_at, Answer, NoConfig, ParseError, Solution, SolveError};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Sums a line of numbers for part 1; part 2 has no answer for odd sums.
+struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 0;
+    const NAME: &'static str = "sum";
+
+    type Input = Vec<i64>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split_whitespace()
+            .map(|n| parse_at(input, n, "a number"))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input, _config: &NoConfig) -> Result<Answer, SolveError> {
+        Ok(input.iter().sum::<i64>().into())
+    }
+
+    fn part2(input: &Self::Input, _config: &NoConfig) -> Result<Answer, SolveError> {
+        match input.iter().sum::<i64>() {
+            sum if sum % 2 == 0 => Ok((sum / 2).into()),
+            _ => Err(SolveError::NoSolution(String::from("odd sum"))),
+        }
+    }
+}
+
+/// A fresh directory of inputs, named after the test using it.
+fn inputs(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}-{}", test, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for (name, contents) in files {
+        fs::write(dir.join(name), contents).unwrap();
+    }
+    dir
+}
+
+fn solve(input: &str) -> Result<cli::Report, String> {
+    cli::solve::<Sum>(input, &[1, 2], &NoConfig::default()).map_err(|e| e.to_string())
+}
+
+#[test]
+fn expands_directories_and_globs_in_order() {
+    let dir = inputs("expand", &[("b.txt", ""), ("a.txt", ""), ("c.in", "")]);
+    let names = |pattern: &str| {
+        batch::expand(pattern)
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(names(dir.to_str().unwrap()), ["a.txt", "b.txt", "c.in"]);
+    assert_eq!(
+        names(&format!("{}/*.txt", dir.display())),
+        ["a.txt", "b.txt"]
+    );
+    assert_eq!(names(&format!("{}/c.in", dir.display())), ["c.in"]);
+    assert!(batch::expand(&format!("{}/*.md", dir.display())).is_err());
+}
+
+#[test]
+fn carries_on_past_failures() {
+    let dir = inputs(
+        "run",
+        &[("1.txt", "1 3"), ("2.txt", "1 x"), ("3.txt", "1 2")],
+    );
+    let rows = batch::run(&batch::expand(dir.to_str().unwrap()).unwrap(), solve);
+    assert_eq!(rows.len(), 3);
+    assert!(rows[0].is_ok());
+    assert_eq!(rows[0].part2, Some(Answer::Number(2)));
+    assert_eq!(
+        rows[1].errors,
+        ["line 1, column 3: expected a number: \"x\""]
+    );
+    assert_eq!(rows[2].part1, Some(Answer::Number(3)));
+    assert_eq!(rows[2].errors, ["part 2: no solution: odd sum"]);
+}
+
+fn row(file: &str, part1: Answer, errors: &[&str]) -> Row {
+    Row {
+        file: String::from(file),
+        part1: Some(part1),
+        part2: None,
+        time: Duration::from_micros(1500),
+        errors: errors.iter().map(|e| e.to_string()).collect(),
+    }
+}
+
+#[test]
+fn lines_up_tables() {
+    let rows = [
+        row("a.txt", Answer::Number(24000), &[]),
+        row(
+            "long-name.txt",
+            Answer::Image(vec![String::from("#.")]),
+            &["part 2: no"],
+        ),
+    ];
+    assert_eq!(
+        batch::to_table(&rows),
+        "file           part1          part2  time_ms  errors\n\
+         a.txt          24000                 1.500\n\
+         long-name.txt  <1-row image>         1.500    part 2: no\n"
+    );
+}
+
+#[test]
+fn quotes_csv_fields() {
+    let rows = [row(
+        "a,b.txt",
+        Answer::Text(String::from("C\"MZ")),
+        &["x", "y"],
+    )];
+    assert_eq!(
+        batch::to_csv(&rows),
+        "file,part1,part2,time_ms,errors\n\"a,b.txt\",\"C\"\"MZ\",,1.500,x; y\n"
+    );
+}
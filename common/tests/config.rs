@@ -0,0 +1,57 @@
+use common::config::Overrides;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    row: i64,
+    start: String,
+    samples: Vec<i64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            row: 2000000,
+            start: String::from("AA"),
+            samples: vec![20, 60],
+        }
+    }
+}
+
+#[test]
+fn defaults_fill_missing_keys() {
+    let config = Overrides::new().resolve::<Config>().unwrap();
+    assert_eq!(config, Config::default());
+}
+
+#[test]
+fn later_overrides_win() {
+    let mut overrides = Overrides::parse("row = 10\nstart = \"BB\"").unwrap();
+    overrides.set("row=11").unwrap();
+    overrides.set("samples = [1, 2]").unwrap();
+    overrides.set("start=CC").unwrap();
+    let config = overrides.resolve::<Config>().unwrap();
+    assert_eq!(
+        config,
+        Config {
+            row: 11,
+            start: String::from("CC"),
+            samples: vec![1, 2],
+        }
+    );
+}
+
+#[test]
+fn rejects_bad_overrides() {
+    let mut overrides = Overrides::new();
+    assert!(overrides.set("row").is_err());
+    assert!(overrides.set("=1").is_err());
+    overrides.set("rows=10").unwrap();
+    assert!(overrides.resolve::<Config>().is_err());
+    assert!(Overrides::parse("row = ").is_err());
+
+    let mut overrides = Overrides::new();
+    overrides.set("row=ten").unwrap();
+    assert!(overrides.resolve::<Config>().is_err());
+}
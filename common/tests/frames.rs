@@ -0,0 +1,53 @@
+use common::frames::{Frame, Record, Recording};
+
+struct Counter(usize);
+
+impl Record for Counter {
+    fn frame(&self) -> Frame {
+        Frame {
+            rows: vec![self.0.to_string()],
+        }
+    }
+}
+
+fn record(steps: usize, recording: &mut Recording) -> Vec<String> {
+    let mut counter = Counter(0);
+    recording.step(&counter);
+    for _ in 0..steps {
+        counter.0 += 1;
+        recording.step(&counter);
+    }
+    recording.finish(&counter);
+    recording
+        .frames()
+        .iter()
+        .map(|frame| frame.rows[0].clone())
+        .collect()
+}
+
+#[test]
+fn keeps_every_step_under_the_limit() {
+    let frames = record(3, &mut Recording::new(10));
+    assert_eq!(frames, ["0", "1", "2", "3"]);
+}
+
+#[test]
+fn keeps_the_final_state() {
+    let frames = record(4, &mut Recording::new(10).every(3));
+    assert_eq!(frames, ["0", "3", "4"]);
+}
+
+#[test]
+fn thins_long_recordings_evenly() {
+    let mut recording = Recording::new(4);
+    let frames = record(9, &mut recording);
+    assert_eq!(frames, ["0", "4", "8", "9"]);
+    assert_eq!(recording.steps(), 10);
+}
+
+#[test]
+fn measures_frames() {
+    let frame = Frame::from_text("#..\n.#\n");
+    assert_eq!((frame.width(), frame.height()), (3, 2));
+    assert_eq!(frame.to_string(), "#..\n.#\n");
+}
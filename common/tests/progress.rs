@@ -0,0 +1,100 @@
+use common::progress::Update;
+use common::{cli, parse_at, Answer, NoConfig, ParseError, Progress, Solution, SolveError};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Counts up to its input, reporting each step; part 2 never reports.
+struct Count;
+
+impl Solution for Count {
+    const DAY: u8 = 0;
+    const NAME: &'static str = "count";
+
+    type Input = u64;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_at(input, input.trim(), "a number")
+    }
+
+    fn part1(n: &u64, config: &NoConfig) -> Result<Answer, SolveError> {
+        Self::part1_with(n, config, &Progress::new())
+    }
+
+    fn part2(n: &u64, _config: &NoConfig) -> Result<Answer, SolveError> {
+        Ok((*n).into())
+    }
+
+    fn part1_with(n: &u64, _config: &NoConfig, progress: &Progress) -> Result<Answer, SolveError> {
+        for i in 1..=*n {
+            progress.report(i, *n);
+            if progress.is_cancelled() {
+                return Err(SolveError::Cancelled(format!("counted to {}", i)));
+            }
+        }
+        Ok((*n).into())
+    }
+}
+
+#[test]
+fn reports_each_update() {
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&updates);
+    let progress = Progress::new().on_update(move |update| {
+        seen.lock().unwrap().push(update);
+        true
+    });
+    assert_eq!(Count::part1_with(&3, &NoConfig {}, &progress), Ok(3.into()));
+    let updates = updates.lock().unwrap();
+    assert_eq!(updates.len(), 3);
+    assert_eq!(updates[2], Update { done: 3, total: 3 });
+    assert_eq!(updates[0].fraction(), 1.0 / 3.0);
+}
+
+#[test]
+fn callback_cancels() {
+    let progress = Progress::new().on_update(|update| update.done < 2);
+    assert_eq!(
+        Count::part1_with(&10, &NoConfig {}, &progress),
+        Err(SolveError::Cancelled(String::from("counted to 2")))
+    );
+    assert!(progress.is_cancelled());
+}
+
+#[test]
+fn clones_share_cancellation() {
+    let progress = Progress::new();
+    let handle = progress.clone();
+    assert!(!progress.is_cancelled());
+    handle.cancel();
+    assert!(progress.is_cancelled());
+}
+
+#[test]
+fn timeout_cancels() {
+    let progress = Progress::new().timeout(Duration::ZERO);
+    assert!(progress.is_cancelled());
+    assert!(!Progress::new()
+        .timeout(Duration::from_secs(60))
+        .is_cancelled());
+}
+
+#[test]
+fn later_parts_are_not_started() {
+    let progress = Progress::new();
+    progress.cancel();
+    let report = cli::solve_with::<Count>("5", &[1, 2], &NoConfig {}, &progress).unwrap();
+    assert!(report.is_cancelled());
+    assert_eq!(
+        report.parts[1].answer,
+        Err(SolveError::Cancelled(String::from("not started")))
+    );
+}
+
+#[test]
+fn parses_timeouts() {
+    assert_eq!(cli::parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+    assert!(cli::parse_timeout("0").is_err());
+    assert!(cli::parse_timeout("-1").is_err());
+    assert!(cli::parse_timeout("soon").is_err());
+}
@@ -0,0 +1,89 @@
+//! Exports of the `day` world for whichever day's feature is enabled.
+
+use common::{Progress, Solution};
+use std::time::Duration;
+
+/// Exports the first day whose feature is enabled, so the crate still builds
+/// under `--all-features`. A component can only export one day, though, so
+/// for wasm targets a second day feature is an error.
+macro_rules! export_day {
+    ($feature:literal => $puzzle:path, $($rest:literal => $rest_puzzle:path,)*) => {
+        #[cfg(feature = $feature)]
+        use $puzzle as Puzzle;
+        $(
+            #[cfg(all(target_family = "wasm", feature = $feature, feature = $rest))]
+            compile_error!(concat!(
+                "enable one day per component build, not both ",
+                $feature,
+                " and ",
+                $rest
+            ));
+        )*
+        #[cfg(not(feature = $feature))]
+        export_day!($($rest => $rest_puzzle,)*);
+    };
+    () => {};
+}
+
+export_day!(
+    "beacon-exclusion-zone" => beacon_exclusion_zone::Puzzle,
+    "calorie-counting" => calorie_counting::Puzzle,
+    "camp-cleanup" => camp_cleanup::Puzzle,
+    "cathode-ray-tube" => cathode_ray_tube::Puzzle,
+    "distress-signal" => distress_signal::Puzzle,
+    "hill-climbing-algorithm" => hill_climbing_algorithm::Puzzle,
+    "monkey-in-the-middle" => monkey_in_the_middle::Puzzle,
+    "no-space-left-on-device" => no_space_left_on_device::Puzzle,
+    "proboscidea-volcanium" => proboscidea_volcanium::Puzzle,
+    "regolith-resevoir" => regolith_resevoir::Puzzle,
+    "rock-paper-scissors" => rock_paper_scissors::Puzzle,
+    "rope-bridge" => rope_bridge::Puzzle,
+    "rucksack-reorganization" => rucksack_reorganization::Puzzle,
+    "supply-stacks" => supply_stacks::Puzzle,
+    "treetop-tree-house" => treetop_tree_house::Puzzle,
+    "tuning-trouble" => tuning_trouble::Puzzle,
+);
+
+wit_bindgen::generate!({
+    world: "day",
+    path: "../wit",
+});
+
+struct Day;
+
+impl Guest for Day {
+    fn day() -> u8 {
+        Puzzle::DAY
+    }
+
+    fn name() -> String {
+        Puzzle::NAME.to_string()
+    }
+
+    fn solve(input: String, part: u8) -> Result<String, Error> {
+        solve_with(&input, part, Progress::new())
+    }
+
+    fn solve_within(input: String, part: u8, timeout_ms: u32) -> Result<String, Error> {
+        let timeout = Duration::from_millis(timeout_ms.into());
+        solve_with(&input, part, Progress::new().timeout(timeout))
+    }
+}
+
+/// Solves under `watch`, passing its reports on to the host.
+fn solve_with(input: &str, part: u8, watch: Progress) -> Result<String, Error> {
+    let watch = watch.on_update(|update| progress(update.done, update.total));
+    crate::solve_with::<Puzzle>(input, part, &watch).map_err(|failure| match failure {
+        crate::Failure::Parse(e) => Error::Parse(ParseError {
+            line: e.line as u32,
+            column: e.column as u32,
+            text: e.text,
+            message: e.message,
+        }),
+        crate::Failure::NoSolution(reason) => Error::NoSolution(reason),
+        crate::Failure::InvalidPart(part) => Error::InvalidPart(part),
+        crate::Failure::Cancelled(partial) => Error::Cancelled(partial),
+    })
+}
+
+export!(Day);
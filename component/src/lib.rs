@@ -0,0 +1,63 @@
+//! Each day as a WebAssembly component implementing the `day` world in
+//! `wit/day.wit`.
+//!
+//! Enable one day's feature and build for `wasm32-wasip2`; the cdylib is then a
+//! component that any host can call without linking Rust. Without a day
+//! feature only the target-independent [`solve`] is built.
+
+use common::{Answer, ParseError, Progress, Solution, SolveError};
+
+#[cfg(feature = "guest")]
+mod guest;
+
+/// Why a part couldn't be solved, mirroring the world's `error` variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Parse(ParseError),
+    NoSolution(String),
+    InvalidPart(u8),
+    Cancelled(String),
+}
+
+impl From<ParseError> for Failure {
+    fn from(e: ParseError) -> Self {
+        Failure::Parse(e)
+    }
+}
+
+impl From<SolveError> for Failure {
+    fn from(e: SolveError) -> Self {
+        match e {
+            SolveError::NoSolution(reason) => Failure::NoSolution(reason),
+            SolveError::Cancelled(partial) => Failure::Cancelled(partial),
+        }
+    }
+}
+
+/// Solves `part` of `input` under `S`'s default constants, rendering the answer
+/// as a string. Image rows are joined by newlines, without a trailing one.
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<String, Failure> {
+    solve_with::<S>(input, part, &Progress::new())
+}
+
+/// Like [`solve`], reporting to `progress`; the guest hooks it up to the
+/// world's `progress` import.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    part: u8,
+    progress: &Progress,
+) -> Result<String, Failure> {
+    if !(1..=2).contains(&part) {
+        return Err(Failure::InvalidPart(part));
+    }
+    let parsed = S::parse(input)?;
+    let config = S::Config::default();
+    let answer = match part {
+        1 => S::part1_with(&parsed, &config, progress),
+        _ => S::part2_with(&parsed, &config, progress),
+    }?;
+    Ok(match answer {
+        Answer::Image(rows) => rows.join("\n"),
+        answer => answer.to_string(),
+    })
+}
@@ -0,0 +1,49 @@
+use common::Progress;
+use component::{solve, solve_with, Failure};
+use std::fs;
+
+#[test]
+fn renders_numbers() {
+    let input = fs::read_to_string("../calorie-counting/fixtures/example/input.txt").unwrap();
+    assert_eq!(
+        solve::<calorie_counting::Puzzle>(&input, 1).unwrap(),
+        "24000"
+    );
+    assert_eq!(
+        solve::<calorie_counting::Puzzle>(&input, 2).unwrap(),
+        "45000"
+    );
+}
+
+#[test]
+fn renders_images_without_trailing_newline() {
+    let dir = "../cathode-ray-tube/fixtures/example";
+    let input = fs::read_to_string(format!("{}/input.txt", dir)).unwrap();
+    let expected = fs::read_to_string(format!("{}/part2.txt", dir)).unwrap();
+    assert_eq!(
+        solve::<cathode_ray_tube::Puzzle>(&input, 2).unwrap(),
+        expected.trim_end()
+    );
+}
+
+#[test]
+fn reports_failures() {
+    assert!(matches!(
+        solve::<calorie_counting::Puzzle>("1000\nabc\n", 1),
+        Err(Failure::Parse(e)) if e.line == 2
+    ));
+    assert_eq!(
+        solve::<calorie_counting::Puzzle>("1000\n", 3),
+        Err(Failure::InvalidPart(3))
+    );
+}
+
+#[test]
+fn reports_cancellation() {
+    let input = fs::read_to_string("../proboscidea-volcanium/fixtures/example/input.txt").unwrap();
+    let progress = Progress::new().on_update(|_| false);
+    assert!(matches!(
+        solve_with::<proboscidea_volcanium::Puzzle>(&input, 2, &progress),
+        Err(Failure::Cancelled(partial)) if partial.contains("first valves")
+    ));
+}
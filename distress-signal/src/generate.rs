@@ -0,0 +1,26 @@
+//! `size` pairs of random packets nested up to four lists deep.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+fn list(rng: &mut Rng, depth: usize) -> String {
+    let values = (0..rng.range(0..=4))
+        .map(|_| {
+            if depth < 3 && rng.chance(0.3) {
+                list(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(","))
+}
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", list(rng, 0), list(rng, 0)))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,258 @@
+use common::{Answer, NoConfig, ParseError, Solution, SolveError};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+mod generate;
+mod reference;
+
+#[derive(Debug, Clone)]
+pub enum Packet<T> {
+    List(Vec<Rc<RefCell<Packet<T>>>>),
+    Atom(T),
+}
+
+pub type PacketPair = (Packet<i64>, Packet<i64>);
+
+impl PartialOrd for Packet<i64> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Self::compare_packets(self, other))
+    }
+}
+
+impl PartialEq for Packet<i64> {
+    fn eq(&self, other: &Self) -> bool {
+        Self::compare_packets(self, other) == Ordering::Equal
+    }
+}
+
+impl Packet<i64> {
+    fn compare_packets(p1: &Packet<i64>, p2: &Packet<i64>) -> Ordering {
+        match (p1, p2) {
+            (Packet::Atom(v1), Packet::Atom(v2)) => v1.cmp(v2),
+            (Packet::Atom(v1), Packet::List(l2)) => {
+                let l1 = vec![Rc::new(RefCell::new(Packet::Atom(*v1)))];
+                Self::compare_packet_list_values(&l1, l2)
+            }
+            (Packet::List(l1), Packet::Atom(v2)) => {
+                let l2 = vec![Rc::new(RefCell::new(Packet::Atom(*v2)))];
+                Self::compare_packet_list_values(l1, &l2)
+            }
+            (Packet::List(l1), Packet::List(l2)) => Self::compare_packet_list_values(l1, l2),
+        }
+    }
+
+    fn compare_packet_list_values(
+        l1: &[Rc<RefCell<Packet<i64>>>],
+        l2: &[Rc<RefCell<Packet<i64>>>],
+    ) -> Ordering {
+        let mut i = 0;
+        while i < l1.len() && i < l2.len() {
+            let p1 = l1[i].borrow();
+            let p2 = l2[i].borrow();
+            let res = Self::compare_packets(&p1, &p2);
+            if res != Ordering::Equal {
+                return res;
+            } else {
+                i += 1
+            }
+        }
+        l1.len().cmp(&l2.len())
+    }
+}
+
+/// How deeply lists may nest. Parsing, comparing and dropping packets all
+/// recurse, so unbounded nesting would overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+/// A parsed packet and the index just past it, or the index and reason of a failure.
+type PacketResult = Result<(Packet<i64>, usize), (usize, &'static str)>;
+
+fn parse_packet_line(s: &str, l: &str) -> Result<Packet<i64>, ParseError> {
+    let char_buffer = l.chars().collect::<Vec<char>>();
+    let res = parse_packet(&char_buffer, 0, 0).and_then(|(p, idx)| {
+        if idx == char_buffer.len() {
+            Ok(p)
+        } else {
+            Err((idx, "unexpected characters after packet"))
+        }
+    });
+    res.map_err(|(idx, message)| {
+        let offset = l
+            .char_indices()
+            .nth(idx)
+            .map_or(l.len(), |(offset, _)| offset);
+        ParseError::at(s, &l[offset..], message)
+    })
+}
+
+fn parse_packet_pairs(s: &str) -> Result<Vec<PacketPair>, ParseError> {
+    let mut packet_pairs: Vec<PacketPair> = Vec::new();
+    let mut lines_buffer: Vec<&str> = Vec::new();
+    for l in s.lines().chain(std::iter::once("")) {
+        if l.is_empty() {
+            match lines_buffer[..] {
+                [] => {}
+                [l1, l2] => {
+                    packet_pairs.push((parse_packet_line(s, l1)?, parse_packet_line(s, l2)?));
+                }
+                [l1] => {
+                    return Err(ParseError::at(
+                        s,
+                        l1,
+                        "expected a second packet in the pair",
+                    ))
+                }
+                [_, _, l3, ..] => {
+                    return Err(ParseError::at(s, l3, "expected a blank line between pairs"))
+                }
+            }
+            lines_buffer.clear();
+        } else {
+            lines_buffer.push(l);
+        }
+    }
+    Ok(packet_pairs)
+}
+
+fn parse_packet(char_buffer: &[char], idx: usize, depth: usize) -> PacketResult {
+    match char_buffer.get(idx) {
+        Some('[') => parse_packet_list(char_buffer, idx, depth),
+        Some('-' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' | '0') => {
+            parse_packet_atom(char_buffer, idx)
+        }
+        _ => Err((idx, "expected a list or an integer")),
+    }
+}
+
+fn parse_packet_atom(char_buffer: &[char], idx: usize) -> PacketResult {
+    let mut curr_idx = idx;
+    let mut digit_buffer = String::new();
+    loop {
+        match char_buffer.get(curr_idx) {
+            Some(&c @ ('-' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' | '0')) => {
+                digit_buffer.push(c);
+                curr_idx += 1;
+            }
+            _ => {
+                let res = digit_buffer.parse::<i64>();
+                match res {
+                    Ok(v) => {
+                        let p = Packet::Atom(v);
+                        return Ok((p, curr_idx));
+                    }
+                    _ => return Err((idx, "invalid integer")),
+                }
+            }
+        }
+    }
+}
+
+fn parse_packet_list(char_buffer: &[char], idx: usize, depth: usize) -> PacketResult {
+    if depth >= MAX_DEPTH {
+        return Err((idx, "lists nest too deeply"));
+    }
+    let mut data = Vec::new();
+    let mut curr_idx = idx;
+    match char_buffer.get(curr_idx) {
+        Some('[') => {
+            curr_idx += 1;
+            loop {
+                match char_buffer.get(curr_idx) {
+                    Some(']') => {
+                        let mut l = Vec::new();
+                        for p in data {
+                            let p_rc = Rc::new(RefCell::new(p));
+                            l.push(p_rc);
+                        }
+                        let p = Packet::List(l);
+                        return Ok((p, curr_idx + 1));
+                    }
+                    _ => {
+                        let (p, idx) = parse_packet(char_buffer, curr_idx, depth + 1)?;
+                        data.push(p);
+                        match char_buffer.get(idx) {
+                            Some(',') => {
+                                curr_idx = idx + 1;
+                            }
+                            Some(']') => {
+                                curr_idx = idx;
+                            }
+                            _ => return Err((idx, "expected ',' or ']'")),
+                        }
+                    }
+                }
+            }
+        }
+        _ => Err((curr_idx, "expected '['")),
+    }
+}
+
+pub fn divider_packets() -> PacketPair {
+    let divider_p1 = Packet::List(vec![Rc::new(RefCell::new(Packet::List(vec![Rc::new(
+        RefCell::new(Packet::Atom(2)),
+    )])))]);
+    let divider_p2 = Packet::List(vec![Rc::new(RefCell::new(Packet::List(vec![Rc::new(
+        RefCell::new(Packet::Atom(6)),
+    )])))]);
+    (divider_p1, divider_p2)
+}
+
+pub fn parse(input: &str) -> Result<Vec<PacketPair>, ParseError> {
+    parse_packet_pairs(input)
+}
+
+pub fn part1(packet_pairs: &[PacketPair]) -> usize {
+    let mut s = 0;
+    for (i, packet_pair) in packet_pairs.iter().enumerate() {
+        let (p1, p2) = packet_pair;
+        if p1 < p2 {
+            s += i + 1;
+        }
+    }
+    s
+}
+
+pub fn part2(packet_pairs: &[PacketPair]) -> usize {
+    let (divider_p1, divider_p2) = divider_packets();
+    // Dividers are tagged rather than found by comparison, since packets like
+    // `[2]` compare equal to them. They go in first so the stable sort keeps
+    // them ahead of any such packet.
+    let mut all_packets = vec![(divider_p1, true), (divider_p2, true)];
+    for packet_pair in packet_pairs {
+        let (p1, p2) = packet_pair;
+        all_packets.push((p1.clone(), false));
+        all_packets.push((p2.clone(), false));
+    }
+    all_packets.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+    let mut m = 1;
+    for (i, (_, is_divider)) in all_packets.iter().enumerate() {
+        if *is_divider {
+            m *= i + 1
+        }
+    }
+    m
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 13;
+    const NAME: &'static str = "distress-signal";
+
+    type Input = Vec<PacketPair>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(packet_pairs: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(packet_pairs).into())
+    }
+
+    fn part2(packet_pairs: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(packet_pairs).into())
+    }
+}
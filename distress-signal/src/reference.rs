@@ -0,0 +1,56 @@
+//! Orders packets with a direct recursive comparison and finds the dividers
+//! by counting the packets before them instead of sorting.
+
+use crate::{divider_packets, Packet, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+use std::cmp::Ordering;
+
+fn compare(left: &Packet<i64>, right: &Packet<i64>) -> Ordering {
+    match (left, right) {
+        (Packet::Atom(a), Packet::Atom(b)) => a.cmp(b),
+        (Packet::List(a), Packet::List(b)) => {
+            for (x, y) in a.iter().zip(b) {
+                let order = compare(&x.borrow(), &y.borrow());
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+            a.len().cmp(&b.len())
+        }
+        (Packet::Atom(_), Packet::List(_)) => compare(&wrap(left), right),
+        (Packet::List(_), Packet::Atom(_)) => compare(left, &wrap(right)),
+    }
+}
+
+fn wrap(atom: &Packet<i64>) -> Packet<i64> {
+    Packet::List(vec![std::rc::Rc::new(std::cell::RefCell::new(
+        atom.clone(),
+    ))])
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(pairs: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let mut total = 0;
+        for (i, (left, right)) in pairs.iter().enumerate() {
+            if compare(left, right) == Ordering::Less {
+                total += i + 1;
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn reference_part2(pairs: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let (first, second) = divider_packets();
+        let packets = pairs.iter().flat_map(|(left, right)| [left, right]);
+        let before = |divider: &Packet<i64>| {
+            packets
+                .clone()
+                .filter(|packet| compare(packet, divider) == Ordering::Less)
+                .count()
+        };
+        // The first divider sits before the second, adding one to its index.
+        let product = (before(&first) + 1) * (before(&second) + 2);
+        Ok(product.into())
+    }
+}
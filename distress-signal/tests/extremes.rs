@@ -0,0 +1,9 @@
+use common::Solution;
+use distress_signal::Puzzle;
+
+#[test]
+fn compares_integers_at_the_ends_of_i64() {
+    let input = format!("[{}]\n[1]\n\n[{}]\n[-1]\n", i64::MIN, i64::MAX);
+    let packets = Puzzle::parse(&input).unwrap();
+    assert_eq!(Puzzle::part1(&packets, &Default::default()), Ok(1.into()));
+}
@@ -0,0 +1,6 @@
+use distress_signal::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
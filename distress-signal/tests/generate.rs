@@ -0,0 +1,15 @@
+use common::generate::generate;
+use common::Solution;
+use distress_signal::{self as day, Puzzle};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..40) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        prop_assert!(day::part1(&input) <= size * (size + 1) / 2);
+        prop_assert!(day::part2(&input) >= 2);
+    }
+}
@@ -0,0 +1,11 @@
+use distress_signal::parse;
+
+#[test]
+fn rejects_deep_nesting_without_overflowing() {
+    let input = format!("{}\n[]\n", "[".repeat(100000));
+    let e = parse(&input).unwrap_err();
+    assert_eq!(e.message, "lists nest too deeply");
+
+    let input = format!("{}{}\n[]\n", "[".repeat(100), "]".repeat(100));
+    assert!(parse(&input).is_ok());
+}
@@ -0,0 +1,274 @@
+//! Shortest-path searches shared by the graph puzzles.
+//!
+//! Anything that can list a node's outgoing edges implements [`Graph`]. The
+//! crate ships an [`AdjacencyList`] for named graphs and a [`GridGraph`] that
+//! walks between orthogonal neighbors of a [`Grid`].
+
+use grid::{Grid, Pos};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A directed graph with non-negative edge weights.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+    /// Per-node storage for search results.
+    type Map<V>: NodeMap<Self::Node, V>;
+
+    /// The `(neighbor, weight)` pairs reachable from `node` in one step.
+    fn edges(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+
+    /// An empty map able to hold a value for every node.
+    fn node_map<V>(&self) -> Self::Map<V>;
+}
+
+/// A map from nodes to values, letting dense graphs skip hashing.
+pub trait NodeMap<N, V> {
+    fn get(&self, node: &N) -> Option<&V>;
+    fn insert(&mut self, node: N, value: V);
+}
+
+impl<N: Eq + Hash, V> NodeMap<N, V> for HashMap<N, V> {
+    fn get(&self, node: &N) -> Option<&V> {
+        HashMap::get(self, node)
+    }
+
+    fn insert(&mut self, node: N, value: V) {
+        HashMap::insert(self, node, value);
+    }
+}
+
+impl<V> NodeMap<Pos, V> for Grid<Option<V>> {
+    fn get(&self, &pos: &Pos) -> Option<&V> {
+        Grid::get(self, pos)?.as_ref()
+    }
+
+    fn insert(&mut self, pos: Pos, value: V) {
+        self[pos] = Some(value);
+    }
+}
+
+/// A graph stored as a map from each node to its outgoing edges.
+#[derive(Debug, Clone)]
+pub struct AdjacencyList<N> {
+    edges: HashMap<N, Vec<(N, u64)>>,
+}
+
+impl<N: Clone + Eq + Hash> AdjacencyList<N> {
+    pub fn new() -> Self {
+        AdjacencyList {
+            edges: HashMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.edges.entry(node).or_default();
+    }
+
+    /// Adds a directed edge, creating either end if it is new.
+    pub fn add_edge(&mut self, from: N, to: N, weight: u64) {
+        self.add_node(to.clone());
+        self.edges.entry(from).or_default().push((to, weight));
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for AdjacencyList<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph for AdjacencyList<N> {
+    type Node = N;
+    type Map<V> = HashMap<N, V>;
+
+    fn edges(&self, node: &N) -> impl Iterator<Item = (N, u64)> {
+        self.edges.get(node).into_iter().flatten().cloned()
+    }
+
+    fn node_map<V>(&self) -> HashMap<N, V> {
+        HashMap::new()
+    }
+}
+
+/// Unit-weight moves between orthogonal neighbors of a grid.
+///
+/// `can_move(from, to)` decides whether a step between two cells is allowed.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    can_move: F,
+}
+
+impl<'a, T, F: Fn(&T, &T) -> bool> GridGraph<'a, T, F> {
+    pub fn new(grid: &'a Grid<T>, can_move: F) -> Self {
+        GridGraph { grid, can_move }
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> bool> Graph for GridGraph<'_, T, F> {
+    type Node = Pos;
+    type Map<V> = Grid<Option<V>>;
+
+    fn edges(&self, &pos: &Pos) -> impl Iterator<Item = (Pos, u64)> {
+        self.grid
+            .neighbors4(pos)
+            .filter(move |&next| (self.can_move)(&self.grid[pos], &self.grid[next]))
+            .map(|next| (next, 1))
+    }
+
+    fn node_map<V>(&self) -> Grid<Option<V>> {
+        Grid::from_fn(self.grid.height(), self.grid.width(), |_| None)
+    }
+}
+
+/// Distances from a search's start, with enough bookkeeping to rebuild paths.
+pub struct ShortestPaths<G: Graph> {
+    distances: G::Map<u64>,
+    previous: G::Map<G::Node>,
+}
+
+impl<G: Graph> ShortestPaths<G> {
+    fn new(graph: &G, start: G::Node) -> Self {
+        let mut distances = graph.node_map();
+        distances.insert(start, 0);
+        ShortestPaths {
+            distances,
+            previous: graph.node_map(),
+        }
+    }
+
+    /// The distance to `node`, or `None` if it is unreachable.
+    pub fn distance(&self, node: &G::Node) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// The nodes from the start to `node`, both included.
+    pub fn path_to(&self, node: &G::Node) -> Option<Vec<G::Node>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.previous.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn into_distances(self) -> G::Map<u64> {
+        self.distances
+    }
+}
+
+/// Breadth-first search from `start`, counting every edge as one step.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> ShortestPaths<G> {
+    let mut paths = ShortestPaths::new(graph, start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let dist = paths.distance(&node).unwrap();
+        for (next, _) in graph.edges(&node) {
+            if paths.distance(&next).is_none() {
+                paths.distances.insert(next.clone(), dist + 1);
+                paths.previous.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// A frontier entry ordered so the heap pops the lowest cost first.
+struct State<N> {
+    cost: u64,
+    estimate: u64,
+    node: N,
+}
+
+impl<N> PartialEq for State<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for State<N> {}
+
+impl<N> PartialOrd for State<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for State<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// Best-first search shared by Dijkstra and A*. Stops early once `goal` is
+/// settled.
+fn search<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: Option<&G::Node>,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> ShortestPaths<G> {
+    let mut paths = ShortestPaths::new(graph, start.clone());
+    let mut heap = BinaryHeap::from([State {
+        cost: 0,
+        estimate: heuristic(&start),
+        node: start,
+    }]);
+    while let Some(State { cost, node, .. }) = heap.pop() {
+        if paths.distance(&node).is_some_and(|d| cost > d) {
+            continue;
+        }
+        if goal == Some(&node) {
+            break;
+        }
+        for (next, weight) in graph.edges(&node) {
+            let next_cost = cost + weight;
+            if paths.distance(&next).is_some_and(|d| d <= next_cost) {
+                continue;
+            }
+            paths.distances.insert(next.clone(), next_cost);
+            paths.previous.insert(next.clone(), node.clone());
+            heap.push(State {
+                cost: next_cost,
+                estimate: next_cost + heuristic(&next),
+                node: next,
+            });
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm from `start` over the whole reachable graph.
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node) -> ShortestPaths<G> {
+    search(graph, start, None, |_| 0)
+}
+
+/// A* from `start` to `goal`, returning the cost and path if `goal` is
+/// reachable. `heuristic` must never overestimate the remaining cost.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    goal: &G::Node,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Option<(u64, Vec<G::Node>)> {
+    let paths = search(graph, start, Some(goal), heuristic);
+    Some((paths.distance(goal)?, paths.path_to(goal)?))
+}
+
+/// Distances between every pair of `nodes`, keyed by source then target.
+/// Unreachable targets are left out.
+pub fn all_pairs<G: Graph>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> HashMap<G::Node, G::Map<u64>> {
+    nodes
+        .into_iter()
+        .map(|node| (node.clone(), dijkstra(graph, node).into_distances()))
+        .collect()
+}
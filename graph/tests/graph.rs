@@ -0,0 +1,59 @@
+use graph::{AdjacencyList, Graph, GridGraph};
+use grid::Grid;
+
+fn weighted() -> AdjacencyList<&'static str> {
+    let mut g = AdjacencyList::new();
+    g.add_edge("a", "b", 1);
+    g.add_edge("b", "c", 1);
+    g.add_edge("a", "c", 5);
+    g.add_edge("c", "d", 2);
+    g.add_node("island");
+    g
+}
+
+#[test]
+fn bfs_counts_hops() {
+    let g = weighted();
+    let paths = graph::bfs(&g, "a");
+    assert_eq!(paths.distance(&"c"), Some(1));
+    assert_eq!(paths.distance(&"d"), Some(2));
+    assert_eq!(paths.distance(&"island"), None);
+    assert_eq!(paths.path_to(&"d"), Some(vec!["a", "c", "d"]));
+}
+
+#[test]
+fn dijkstra_follows_weights() {
+    let g = weighted();
+    let paths = graph::dijkstra(&g, "a");
+    assert_eq!(paths.distance(&"a"), Some(0));
+    assert_eq!(paths.distance(&"d"), Some(4));
+    assert_eq!(paths.path_to(&"d"), Some(vec!["a", "b", "c", "d"]));
+    assert_eq!(paths.path_to(&"island"), None);
+}
+
+#[test]
+fn all_pairs_skips_unreachable() {
+    let g = weighted();
+    let all = graph::all_pairs(&g, g.nodes().copied());
+    assert_eq!(all["b"].get("d"), Some(&3));
+    assert_eq!(all["d"].get("a"), None);
+    assert_eq!(all["island"].len(), 1);
+}
+
+#[test]
+fn grid_graph_respects_moves() {
+    let grid = Grid::parse("..#\n.##\n...", "a cell", Some).unwrap();
+    let open = GridGraph::new(&grid, |_, &to| to == '.');
+    assert_eq!(open.edges(&(0, 0)).count(), 2);
+
+    let paths = graph::bfs(&open, (0, 0));
+    assert_eq!(paths.distance(&(2, 2)), Some(4));
+    assert_eq!(paths.distance(&(0, 2)), None);
+
+    let manhattan = |&(r, c): &(usize, usize)| (4 - r - c) as u64;
+    let (cost, path) = graph::astar(&open, (0, 0), &(2, 2), manhattan).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(path.first(), Some(&(0, 0)));
+    assert_eq!(path.last(), Some(&(2, 2)));
+    assert_eq!(graph::astar(&open, (0, 0), &(0, 2), manhattan), None);
+}
@@ -0,0 +1,257 @@
+//! A dense 2D grid shared by the grid puzzles.
+//!
+//! Cells are addressed by `(row, column)` and stored row-major in one `Vec`.
+//! Movement uses signed `(row, column)` offsets, and every step off the grid
+//! is caught by bounds checks rather than wrapping.
+
+use common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell's `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// A signed `(row, column)` step.
+pub type Offset = (i64, i64);
+
+/// Up, down, left and right.
+pub const ORTHOGONAL: [Offset; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// The orthogonal steps followed by the four diagonals.
+pub const ALL_DIRECTIONS: [Offset; 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from equally long rows, or `None` if they are ragged.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a map drawn with one character per cell. `cell` converts each
+    /// character, returning `None` for characters that are not `expected`.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for l in input.lines() {
+            let mut row_width = 0;
+            for (idx, c) in l.char_indices() {
+                let v = cell(c).ok_or_else(|| {
+                    ParseError::at(
+                        input,
+                        &l[idx..idx + c.len_utf8()],
+                        format!("expected {}", expected),
+                    )
+                })?;
+                cells.push(v);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(ParseError::at(
+                        input,
+                        l,
+                        format!("expected a row of {} cells like the first", w),
+                    ));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::at_end(input, "expected a map")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        let (r, c) = pos;
+        r < self.height && c < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The cell `offset` away from `pos`, if it is on the grid.
+    pub fn offset(&self, pos: Pos, offset: Offset) -> Option<Pos> {
+        let (r, c) = pos;
+        let (dr, dc) = offset;
+        let r = usize::try_from(r as i64 + dr).ok()?;
+        let c = usize::try_from(c as i64 + dc).ok()?;
+        if self.contains((r, c)) {
+            Some((r, c))
+        } else {
+            None
+        }
+    }
+
+    /// The neighbors of `pos` reached by each of `offsets` that stay on the grid.
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: Pos,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ORTHOGONAL)
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ALL_DIRECTIONS)
+    }
+
+    /// The cells met walking from `pos` by `step` until the edge, excluding
+    /// `pos` itself. A zero step meets nothing.
+    pub fn ray(&self, pos: Pos, step: Offset) -> impl Iterator<Item = Pos> + '_ {
+        let mut curr = Some(pos).filter(|_| step != (0, 0));
+        std::iter::from_fn(move || {
+            curr = self.offset(curr?, step);
+            curr
+        })
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(c).step_by(self.width.max(1))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position in row-major order whose cell satisfies `f`.
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, v)| f(v)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+/// Draws one line per row with no separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use grid::{Grid, ORTHOGONAL};
+
+fn digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn parses_and_displays() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.height(), grid.width()), (2, 3));
+    assert_eq!(grid[(1, 2)], 6);
+    assert_eq!(grid.row(0), &[1, 2, 3]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), vec![2, 5]);
+    assert_eq!(grid.to_string(), "123\n456\n");
+}
+
+#[test]
+fn reports_parse_errors() {
+    let parse = |input| Grid::parse(input, "a digit", |c| c.to_digit(10));
+    let e = parse("12\n3x\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 2));
+    assert!(parse("12\n3\n").is_err());
+    assert!(parse("").is_err());
+}
+
+#[test]
+fn neighbors_stay_in_bounds() {
+    let grid = digits("123\n456\n789");
+    assert_eq!(grid.neighbors4((0, 0)).count(), 2);
+    assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    assert_eq!(grid.offset((0, 0), (2, 2)), Some((2, 2)));
+}
+
+#[test]
+fn rays_exclude_the_start() {
+    let grid = digits("123\n456\n789");
+    let rays = ORTHOGONAL
+        .iter()
+        .map(|&step| grid.ray((1, 1), step).map(|pos| grid[pos]).collect())
+        .collect::<Vec<Vec<u32>>>();
+    assert_eq!(rays, vec![vec![2], vec![8], vec![4], vec![6]]);
+    assert_eq!(
+        grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+        [(1, 1), (2, 2)]
+    );
+    assert_eq!(grid.ray((0, 0), (0, 0)).count(), 0);
+}
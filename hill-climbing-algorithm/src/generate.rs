@@ -0,0 +1,45 @@
+//! A random heightmap `size` squares on a side.
+//!
+//! Elevation mostly rises with distance from the start, roughened by noise
+//! and the odd cliff, and the summit sits on the highest square. Maps under
+//! about 14 squares across are too small to rise all the way to `z`, so their
+//! summit is usually out of reach.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let side = size.max(2);
+        let start = (rng.below(side), rng.below(side));
+        let mut heights = vec![vec![0; side]; side];
+        for (r, row) in heights.iter_mut().enumerate() {
+            for (c, height) in row.iter_mut().enumerate() {
+                let dist = (r.abs_diff(start.0) + c.abs_diff(start.1)) as i64;
+                *height = (dist - rng.range(0..=2)).clamp(0, 25);
+                if rng.chance(0.05) {
+                    *height = (*height + 3).min(25);
+                }
+            }
+        }
+        heights[start.0][start.1] = 0;
+        let end = (0..side)
+            .flat_map(|r| (0..side).map(move |c| (r, c)))
+            .filter(|&pos| pos != start)
+            .max_by_key(|&(r, c)| heights[r][c])
+            .unwrap();
+
+        let mut map = String::new();
+        for (r, row) in heights.iter().enumerate() {
+            for (c, &height) in row.iter().enumerate() {
+                map.push(match (r, c) {
+                    pos if pos == start => 'S',
+                    pos if pos == end => 'E',
+                    _ => (b'a' + height as u8) as char,
+                });
+            }
+            map.push('\n');
+        }
+        map
+    }
+}
@@ -0,0 +1,123 @@
+use common::{Answer, NoConfig, ParseError, Solution, SolveError};
+use graph::GridGraph;
+use grid::{Grid, Pos};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::instrument;
+
+mod generate;
+mod reference;
+
+#[derive(Debug)]
+pub struct HeightMap {
+    heights: Grid<u64>,
+    start: Pos,
+    end: Pos,
+}
+
+/// Finds the single `marker` in `map`, pointing errors at the second copy.
+fn find_marker(s: &str, map: &Grid<char>, marker: char) -> Result<Pos, ParseError> {
+    let mut positions = map
+        .iter()
+        .filter(|&(_, &c)| c == marker)
+        .map(|(pos, _)| pos);
+    let pos = positions
+        .next()
+        .ok_or_else(|| ParseError::at_end(s, format!("missing marker '{}'", marker)))?;
+    if let Some((r, c)) = positions.next() {
+        let l = s.lines().nth(r).unwrap();
+        let (idx, _) = l.char_indices().nth(c).unwrap();
+        return Err(ParseError::at(
+            s,
+            &l[idx..idx + 1],
+            "marker appears more than once",
+        ));
+    }
+    Ok(pos)
+}
+
+impl HeightMap {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(s, "an elevation from a to z", |c| match c {
+            'S' | 'E' | 'a'..='z' => Some(c),
+            _ => None,
+        })?;
+        let start = find_marker(s, &map, 'S')?;
+        let end = find_marker(s, &map, 'E')?;
+        let heights = map.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            _ => c as u64 - 'a' as u64,
+        });
+        Ok(Self {
+            heights,
+            start,
+            end,
+        })
+    }
+
+    pub fn find_shortest_steps_from_start(&self) -> Option<u64> {
+        let steps_cache = self.build_steps_cache(self.start);
+        steps_cache[self.end]
+    }
+
+    /// Runs one search per lowest point, in parallel with the `parallel`
+    /// feature.
+    pub fn find_shortest_steps_from_any_lowest_point(&self) -> Option<u64> {
+        let starts = self
+            .heights
+            .iter()
+            .filter(|&(_, &height)| height == 0)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<Pos>>();
+        let steps_from = |&start: &Pos| self.build_steps_cache(start)[self.end];
+        #[cfg(feature = "parallel")]
+        return starts.par_iter().filter_map(steps_from).min();
+        #[cfg(not(feature = "parallel"))]
+        return starts.iter().filter_map(steps_from).min();
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    pub fn build_steps_cache(&self, start: Pos) -> Grid<Option<u64>> {
+        let climbable = GridGraph::new(&self.heights, |&from, &to| to <= from + 1);
+        graph::bfs(&climbable, start).into_distances()
+    }
+}
+
+pub fn parse(input: &str) -> Result<HeightMap, ParseError> {
+    HeightMap::parse(input)
+}
+
+pub fn part1(map: &HeightMap) -> Option<u64> {
+    map.find_shortest_steps_from_start()
+}
+
+pub fn part2(map: &HeightMap) -> Option<u64> {
+    map.find_shortest_steps_from_any_lowest_point()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 12;
+    const NAME: &'static str = "hill-climbing-algorithm";
+
+    type Input = HeightMap;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part1(map)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("end is unreachable from start")))
+    }
+
+    fn part2(map: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part2(map).map(Answer::from).ok_or_else(|| {
+            SolveError::NoSolution(String::from("end is unreachable from any lowest point"))
+        })
+    }
+}
@@ -0,0 +1,62 @@
+//! Relaxes every step on the map until no distance improves.
+
+use crate::{HeightMap, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+/// The fewest steps from any of `starts` to the summit.
+fn fewest_steps(map: &HeightMap, starts: &[(usize, usize)]) -> Option<u64> {
+    let (height, width) = (map.heights.height(), map.heights.width());
+    let mut steps = vec![vec![None::<u64>; width]; height];
+    for &(r, c) in starts {
+        steps[r][c] = Some(0);
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for r in 0..height {
+            for c in 0..width {
+                let here = match steps[r][c] {
+                    Some(here) => here,
+                    None => continue,
+                };
+                let neighbors = [
+                    (r.wrapping_sub(1), c),
+                    (r + 1, c),
+                    (r, c.wrapping_sub(1)),
+                    (r, c + 1),
+                ];
+                for (nr, nc) in neighbors {
+                    if nr >= height || nc >= width {
+                        continue;
+                    }
+                    let climbable = map.heights[(nr, nc)] <= map.heights[(r, c)] + 1;
+                    if climbable && steps[nr][nc].is_none_or(|there| here + 1 < there) {
+                        steps[nr][nc] = Some(here + 1);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+    steps[map.end.0][map.end.1]
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(map: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        fewest_steps(map, &[map.start])
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("unreachable")))
+    }
+
+    fn reference_part2(map: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let lowest = map
+            .heights
+            .positions()
+            .filter(|&pos| map.heights[pos] == 0)
+            .collect::<Vec<(usize, usize)>>();
+        fewest_steps(map, &lowest)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("unreachable")))
+    }
+}
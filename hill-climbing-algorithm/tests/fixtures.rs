@@ -0,0 +1,6 @@
+use hill_climbing_algorithm::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,19 @@
+use common::generate::generate;
+use common::Solution;
+use hill_climbing_algorithm::{self as day, Puzzle};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 2usize..40) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        // Small maps often can't reach the summit, but any route from the
+        // start is also a route from some lowest square.
+        if let Some(from_start) = day::part1(&input) {
+            let from_lowest = day::part2(&input).unwrap();
+            prop_assert!(from_lowest <= from_start);
+        }
+    }
+}
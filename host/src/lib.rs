@@ -0,0 +1,133 @@
+//! Runs day components built from the `component` crate under wasmtime.
+//!
+//! Components only see stderr, so a panicking day can still explain itself;
+//! input arrives through `solve` rather than the filesystem.
+
+use std::path::Path;
+use std::time::Duration;
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Engine, Result, Store};
+use wasmtime_wasi::{IoView, WasiCtx, WasiCtxBuilder, WasiView};
+
+wasmtime::component::bindgen!({
+    world: "day",
+    path: "../wit",
+});
+
+/// Answers the world's `progress` import; returning false cancels.
+type OnProgress = Box<dyn FnMut(u64, u64) -> bool + Send>;
+
+struct State {
+    ctx: WasiCtx,
+    table: ResourceTable,
+    on_progress: Option<OnProgress>,
+}
+
+impl DayImports for State {
+    fn progress(&mut self, done: u64, total: u64) -> bool {
+        self.on_progress
+            .as_mut()
+            .is_none_or(|on_progress| on_progress(done, total))
+    }
+}
+
+impl IoView for State {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl WasiView for State {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.ctx
+    }
+}
+
+/// Compiles components and links them against WASI.
+pub struct Host {
+    engine: Engine,
+    linker: Linker<State>,
+}
+
+/// A loaded component, ready to solve inputs.
+pub struct Instance {
+    store: Store<State>,
+    day: Day,
+}
+
+impl Host {
+    pub fn new() -> Result<Self> {
+        let engine = Engine::default();
+        let mut linker = Linker::new(&engine);
+        wasmtime_wasi::add_to_linker_sync(&mut linker)?;
+        Day::add_to_linker(&mut linker, |state: &mut State| state)?;
+        Ok(Host { engine, linker })
+    }
+
+    /// Loads a component from a `.wasm` file.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<Instance> {
+        let component = Component::from_file(&self.engine, path)?;
+        self.instantiate(&component)
+    }
+
+    /// Loads a component from its binary or text encoding.
+    pub fn load_bytes(&self, bytes: &[u8]) -> Result<Instance> {
+        let component = Component::new(&self.engine, bytes)?;
+        self.instantiate(&component)
+    }
+
+    fn instantiate(&self, component: &Component) -> Result<Instance> {
+        let state = State {
+            ctx: WasiCtxBuilder::new().inherit_stderr().build(),
+            table: ResourceTable::new(),
+            on_progress: None,
+        };
+        let mut store = Store::new(&self.engine, state);
+        let day = Day::instantiate(&mut store, component, &self.linker)?;
+        Ok(Instance { store, day })
+    }
+}
+
+impl Instance {
+    pub fn day(&mut self) -> Result<u8> {
+        self.day.call_day(&mut self.store)
+    }
+
+    pub fn name(&mut self) -> Result<String> {
+        self.day.call_name(&mut self.store)
+    }
+
+    /// Solves one part of `input`. The outer error is a trap or a mismatched
+    /// component; the inner one is the day's own answer that it couldn't solve.
+    pub fn solve(&mut self, input: &str, part: u8) -> Result<std::result::Result<String, Error>> {
+        self.day.call_solve(&mut self.store, input, part)
+    }
+
+    /// Like [`solve`](Self::solve), handing `on_progress` each report the day
+    /// makes as `(done, total)`. Returning false cancels, and the day answers
+    /// with what it found so far.
+    pub fn solve_with(
+        &mut self,
+        input: &str,
+        part: u8,
+        on_progress: impl FnMut(u64, u64) -> bool + Send + 'static,
+    ) -> Result<std::result::Result<String, Error>> {
+        self.store.data_mut().on_progress = Some(Box::new(on_progress));
+        let solved = self.day.call_solve(&mut self.store, input, part);
+        self.store.data_mut().on_progress = None;
+        solved
+    }
+
+    /// Like [`solve`](Self::solve), but the day gives up after `timeout` and
+    /// answers [`Error::Cancelled`] with what it found so far.
+    pub fn solve_within(
+        &mut self,
+        input: &str,
+        part: u8,
+        timeout: Duration,
+    ) -> Result<std::result::Result<String, Error>> {
+        let millis = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+        self.day
+            .call_solve_within(&mut self.store, input, part, millis)
+    }
+}
@@ -0,0 +1,70 @@
+//! Runs both parts of a day component:
+//! `host [--timeout SECS] <component.wasm> [input-file]`.
+//! Input comes from stdin when no file is given; each part gets its own
+//! timeout.
+
+use common::cli;
+use common::{OrExit, ParseError, SolveError};
+use host::{Error, Host};
+use std::{env, fs, process};
+
+fn describe(error: Error) -> String {
+    match error {
+        Error::Parse(e) => ParseError {
+            line: e.line as usize,
+            column: e.column as usize,
+            text: e.text,
+            message: e.message,
+        }
+        .to_string(),
+        Error::NoSolution(reason) => SolveError::NoSolution(reason).to_string(),
+        Error::InvalidPart(part) => format!("invalid part: {}", part),
+        Error::Cancelled(partial) => SolveError::Cancelled(partial).to_string(),
+    }
+}
+
+const USAGE: &str = "usage: host [--timeout SECS] <component.wasm> [input-file]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let mut timeout = None;
+    if args.first().map(String::as_str) == Some("--timeout") && args.len() > 1 {
+        timeout = Some(cli::parse_timeout(&args[1]).unwrap_or_else(|e| {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }));
+        args.drain(..2);
+    }
+    let (path, input) = match args.as_slice() {
+        [path] => (path, common::read_stdin()),
+        [path, input_path] => (path, fs::read_to_string(input_path).or_exit()),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    let mut instance = Host::new().and_then(|host| host.load(path)).or_exit();
+    let mut solved = true;
+    for part in 1..=2 {
+        let answer = match timeout {
+            Some(timeout) => instance.solve_within(&input, part, timeout),
+            None => instance.solve(&input, part),
+        };
+        match answer.or_exit() {
+            Ok(answer) if answer.contains('\n') => println!("Part {}:\n{}", part, answer),
+            Ok(answer) => println!("Part {}: {}", part, answer),
+            Err(e @ Error::Parse(_)) => {
+                eprintln!("error: {}", describe(e));
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Part {}: {}", part, describe(e));
+                solved = false;
+            }
+        }
+    }
+    if !solved {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,96 @@
+;; A stand-in for a day component: part 1 echoes its input, part 2 echoes it
+;; once the host's progress callback agrees to go on, and every other part is
+;; invalid. A zero timeout cancels straight away. Hand-written, since building
+;; the real days needs the wasm32-wasip2 target.
+(component
+  (import "progress" (func $progress (param "done" u64) (param "total" u64) (result bool)))
+  (core func $progress-lowered (canon lower (func $progress)))
+  (core instance $host (export "progress" (func $progress-lowered)))
+
+  (core module $m
+    (import "host" "progress" (func $progress (param i64 i64) (result i32)))
+    (memory (export "memory") 1)
+    (global $next (mut i32) (i32.const 1024))
+    (data (i32.const 16) "echo")
+    (data (i32.const 24) "stopped")
+
+    (func (export "cabi_realloc")
+      (param $old i32) (param $old_size i32) (param $align i32) (param $size i32)
+      (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $next) (i32.sub (local.get $align) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get $align))))
+      (global.set $next (i32.add (local.get $ptr) (local.get $size)))
+      (local.get $ptr))
+
+    (func (export "day") (result i32)
+      (i32.const 0))
+
+    ;; Strings and results come back through a return area at address 0.
+    (func (export "name") (result i32)
+      (i32.store (i32.const 0) (i32.const 16))
+      (i32.store (i32.const 4) (i32.const 4))
+      (i32.const 0))
+
+    (func $ok (param $ptr i32) (param $len i32) (result i32)
+      (i32.store8 (i32.const 0) (i32.const 0))
+      (i32.store (i32.const 4) (local.get $ptr))
+      (i32.store (i32.const 8) (local.get $len))
+      (i32.const 0))
+
+    (func $cancelled (result i32)
+      (i32.store8 (i32.const 0) (i32.const 1))
+      (i32.store8 (i32.const 4) (i32.const 3))
+      (i32.store (i32.const 8) (i32.const 24))
+      (i32.store (i32.const 12) (i32.const 7))
+      (i32.const 0))
+
+    (func $solve (export "solve") (param $ptr i32) (param $len i32) (param $part i32) (result i32)
+      (if (i32.eq (local.get $part) (i32.const 1))
+        (then (return (call $ok (local.get $ptr) (local.get $len)))))
+      (if (i32.eq (local.get $part) (i32.const 2))
+        (then
+          (if (call $progress (i64.const 1) (i64.const 2))
+            (then (return (call $ok (local.get $ptr) (local.get $len)))))
+          (return (call $cancelled))))
+      (i32.store8 (i32.const 0) (i32.const 1))
+      (i32.store8 (i32.const 4) (i32.const 2))
+      (i32.store8 (i32.const 8) (local.get $part))
+      (i32.const 0))
+
+    (func (export "solve-within")
+      (param $ptr i32) (param $len i32) (param $part i32) (param $timeout i32) (result i32)
+      (if (i32.eqz (local.get $timeout))
+        (then (return (call $cancelled))))
+      (call $solve (local.get $ptr) (local.get $len) (local.get $part))))
+  (core instance $i (instantiate $m (with "host" (instance $host))))
+
+  (type $parse-error
+    (record
+      (field "line" u32)
+      (field "column" u32)
+      (field "text" string)
+      (field "message" string)))
+  (export $parse-error-export "parse-error" (type $parse-error))
+  (type $error
+    (variant
+      (case "parse" $parse-error-export)
+      (case "no-solution" string)
+      (case "invalid-part" u8)
+      (case "cancelled" string)))
+  (export $error-export "error" (type $error))
+
+  (func (export "day") (result u8)
+    (canon lift (core func $i "day")))
+  (func (export "name") (result string)
+    (canon lift (core func $i "name") (memory $i "memory")))
+  (func (export "solve")
+    (param "input" string) (param "part" u8)
+    (result (result string (error $error-export)))
+    (canon lift (core func $i "solve") (memory $i "memory") (realloc (func $i "cabi_realloc"))))
+  (func (export "solve-within")
+    (param "input" string) (param "part" u8) (param "timeout-ms" u32)
+    (result (result string (error $error-export)))
+    (canon lift (core func $i "solve-within") (memory $i "memory") (realloc (func $i "cabi_realloc")))))
@@ -0,0 +1,59 @@
+use host::{Error, Host};
+use std::time::Duration;
+
+const ECHO: &str = include_str!("fixtures/echo.wat");
+
+#[test]
+fn reads_exports() {
+    let mut instance = Host::new().unwrap().load_bytes(ECHO.as_bytes()).unwrap();
+    assert_eq!(instance.day().unwrap(), 0);
+    assert_eq!(instance.name().unwrap(), "echo");
+}
+
+#[test]
+fn passes_input_and_answer() {
+    let mut instance = Host::new().unwrap().load_bytes(ECHO.as_bytes()).unwrap();
+    let answer = instance.solve("1000\n2000\n", 1).unwrap();
+    assert_eq!(answer.unwrap(), "1000\n2000\n");
+}
+
+#[test]
+fn passes_errors() {
+    let mut instance = Host::new().unwrap().load_bytes(ECHO.as_bytes()).unwrap();
+    let answer = instance.solve("", 3).unwrap();
+    assert!(matches!(answer, Err(Error::InvalidPart(3))));
+}
+
+#[test]
+fn rejects_other_worlds() {
+    let host = Host::new().unwrap();
+    assert!(host.load_bytes(b"(component)").is_err());
+}
+
+#[test]
+fn passes_progress_to_the_host() {
+    let mut instance = Host::new().unwrap().load_bytes(ECHO.as_bytes()).unwrap();
+    let answer = instance.solve_with("input", 2, |done, total| {
+        assert_eq!((done, total), (1, 2));
+        true
+    });
+    assert_eq!(answer.unwrap().unwrap(), "input");
+}
+
+#[test]
+fn host_cancels_through_progress() {
+    let mut instance = Host::new().unwrap().load_bytes(ECHO.as_bytes()).unwrap();
+    let answer = instance.solve_with("input", 2, |_, _| false).unwrap();
+    assert!(matches!(answer, Err(Error::Cancelled(partial)) if partial == "stopped"));
+    // Without a callback the day carries on.
+    assert_eq!(instance.solve("input", 2).unwrap().unwrap(), "input");
+}
+
+#[test]
+fn passes_timeouts() {
+    let mut instance = Host::new().unwrap().load_bytes(ECHO.as_bytes()).unwrap();
+    let answer = instance.solve_within("input", 1, Duration::ZERO).unwrap();
+    assert!(matches!(answer, Err(Error::Cancelled(partial)) if partial == "stopped"));
+    let answer = instance.solve_within("input", 1, Duration::from_secs(1));
+    assert_eq!(answer.unwrap().unwrap(), "input");
+}
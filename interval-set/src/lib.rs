@@ -0,0 +1,176 @@
+//! Sets of integers stored as sorted, disjoint, inclusive ranges.
+//!
+//! Ranges that overlap or touch are merged on the way in, so `(1, 3)` and
+//! `(4, 6)` become `(1, 6)`. Empty ranges (`start > end`) are ignored.
+
+use std::slice;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<(i64, i64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Adds every integer in `start..=end`.
+    pub fn insert(&mut self, start: i64, end: i64) {
+        if start > end {
+            return;
+        }
+        // intervals[lo..hi] overlap or touch the new range
+        let lo = self
+            .intervals
+            .partition_point(|&(_, e)| e < start.saturating_sub(1));
+        let hi = self
+            .intervals
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+        let (mut start, mut end) = (start, end);
+        if lo < hi {
+            start = start.min(self.intervals[lo].0);
+            end = end.max(self.intervals[hi - 1].1);
+        }
+        self.intervals.splice(lo..hi, [(start, end)]);
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn intervals(&self) -> &[(i64, i64)] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// The number of merged ranges.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set. A `u128`, since a set spanning all
+    /// of `i64` holds one more than `u64` can count.
+    pub fn covered_len(&self) -> u128 {
+        self.iter().map(|(s, e)| e.abs_diff(s) as u128 + 1).sum()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let idx = self.intervals.partition_point(|&(_, e)| e < x);
+        self.intervals.get(idx).is_some_and(|&(s, _)| s <= x)
+    }
+
+    /// Whether every integer in `self` is also in `other`.
+    pub fn is_subset(&self, other: &IntervalSet) -> bool {
+        self.iter().all(|(s, e)| {
+            let idx = other.intervals.partition_point(|&(_, oe)| oe < s);
+            other
+                .intervals
+                .get(idx)
+                .is_some_and(|&(os, oe)| os <= s && e <= oe)
+        })
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            let (a_start, a_end) = a[i];
+            let (b_start, b_end) = b[j];
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let intervals = self.iter().flat_map(|bounds| other.gaps(bounds)).collect();
+        IntervalSet { intervals }
+    }
+
+    /// The ranges within `bounds` (inclusive) that the set doesn't cover.
+    pub fn gaps(&self, bounds: (i64, i64)) -> Gaps<'_> {
+        let (start, end) = bounds;
+        let idx = self.intervals.partition_point(|&(_, e)| e < start);
+        Gaps {
+            intervals: self.intervals[idx..].iter(),
+            next: Some(start),
+            end,
+        }
+    }
+}
+
+impl FromIterator<(i64, i64)> for IntervalSet {
+    /// Sorts once and merges, which beats repeated [`IntervalSet::insert`]
+    /// for bulk loads.
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .filter(|&(s, e)| s <= e)
+            .collect::<Vec<(i64, i64)>>();
+        ranges.sort_unstable();
+        let mut intervals: Vec<(i64, i64)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match intervals.last_mut() {
+                Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => intervals.push((start, end)),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<const N: usize> From<[(i64, i64); N]> for IntervalSet {
+    fn from(ranges: [(i64, i64); N]) -> Self {
+        ranges.into_iter().collect()
+    }
+}
+
+/// Uncovered ranges, produced by [`IntervalSet::gaps`].
+pub struct Gaps<'a> {
+    intervals: slice::Iter<'a, (i64, i64)>,
+    next: Option<i64>,
+    end: i64,
+}
+
+impl Iterator for Gaps<'_> {
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<(i64, i64)> {
+        loop {
+            let start = self.next.filter(|&start| start <= self.end)?;
+            match self.intervals.next() {
+                Some(&(s, e)) if s <= self.end => {
+                    self.next = e.checked_add(1);
+                    if s > start {
+                        return Some((start, s - 1));
+                    }
+                }
+                _ => {
+                    self.next = None;
+                    return Some((start, self.end));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use interval_set::IntervalSet;
+
+#[test]
+fn insert_merges_overlapping_and_touching_ranges() {
+    let mut set = IntervalSet::new();
+    set.insert(10, 12);
+    set.insert(1, 3);
+    set.insert(4, 5);
+    set.insert(7, 7);
+    set.insert(9, 0);
+    assert_eq!(set.intervals(), &[(1, 5), (7, 7), (10, 12)]);
+    set.insert(6, 10);
+    assert_eq!(set.intervals(), &[(1, 12)]);
+    assert_eq!(set.covered_len(), 12);
+}
+
+#[test]
+fn covered_len_spans_all_of_i64() {
+    let mut set = IntervalSet::new();
+    set.insert(i64::MIN, i64::MAX);
+    assert_eq!(set.covered_len(), 1 << 64);
+    let split = [(i64::MIN, -1), (1, i64::MAX)]
+        .into_iter()
+        .collect::<IntervalSet>();
+    assert_eq!(split.covered_len(), (1 << 64) - 1);
+}
+
+#[test]
+fn collect_matches_insert() {
+    let ranges = [(5, 9), (-3, 0), (1, 2), (20, 25), (8, 12)];
+    let mut inserted = IntervalSet::new();
+    for (s, e) in ranges {
+        inserted.insert(s, e);
+    }
+    let collected = ranges.into_iter().collect::<IntervalSet>();
+    assert_eq!(inserted, collected);
+    assert_eq!(collected.intervals(), &[(-3, 2), (5, 12), (20, 25)]);
+}
+
+#[test]
+fn set_operations() {
+    let a = IntervalSet::from([(0, 10), (20, 30)]);
+    let b = IntervalSet::from([(5, 25)]);
+    assert_eq!(a.union(&b), IntervalSet::from([(0, 30)]));
+    assert_eq!(a.intersection(&b), IntervalSet::from([(5, 10), (20, 25)]));
+    assert_eq!(a.difference(&b), IntervalSet::from([(0, 4), (26, 30)]));
+    assert_eq!(b.difference(&a), IntervalSet::from([(11, 19)]));
+    assert!(IntervalSet::from([(21, 29)]).is_subset(&a));
+    assert!(!b.is_subset(&a));
+    assert!(IntervalSet::new().is_subset(&a));
+}
+
+#[test]
+fn gaps_and_containment() {
+    let set = IntervalSet::from([(2, 4), (8, 9)]);
+    assert_eq!(
+        set.gaps((0, 12)).collect::<Vec<_>>(),
+        [(0, 1), (5, 7), (10, 12)]
+    );
+    assert_eq!(set.gaps((3, 8)).collect::<Vec<_>>(), [(5, 7)]);
+    assert_eq!(set.gaps((8, 9)).count(), 0);
+    assert!(set.contains(2) && set.contains(9));
+    assert!(!set.contains(5) && !set.contains(10));
+}
+
+#[test]
+fn handles_extreme_bounds() {
+    let set = IntervalSet::from([(i64::MIN, -1), (1, i64::MAX)]);
+    assert_eq!(set.gaps((i64::MIN, i64::MAX)).collect::<Vec<_>>(), [(0, 0)]);
+    assert!(!set.contains(0));
+}
@@ -0,0 +1,57 @@
+//! Random notes for up to eight monkeys holding about `size` items between
+//! them.
+//!
+//! Operations stick to adding small numbers or doubling and tripling, so worry
+//! levels can't overflow while they're relieved after each inspection.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+const DIVISORS: [i64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let n_monkeys = size.clamp(2, 8);
+        let mut divisors = DIVISORS;
+        rng.shuffle(&mut divisors);
+        let items_per_monkey = (size / n_monkeys).max(1) as i64;
+        let mut notes = Vec::new();
+        for (id, divisor) in divisors.iter().enumerate().take(n_monkeys) {
+            let items = (0..rng.range(1..=items_per_monkey))
+                .map(|_| rng.range(50..=99).to_string())
+                .collect::<Vec<String>>();
+            let operation = match rng.below(3) {
+                0 => format!("old + {}", rng.range(1..=8)),
+                _ => format!("old * {}", rng.range(2..=3)),
+            };
+            let other = |rng: &mut Rng, except: &[usize]| loop {
+                let target = rng.below(n_monkeys);
+                if !except.contains(&target) {
+                    break target;
+                }
+            };
+            let true_target = other(rng, &[id]);
+            let false_target = if n_monkeys > 2 {
+                other(rng, &[id, true_target])
+            } else {
+                true_target
+            };
+            notes.push(format!(
+                "Monkey {}:
+  Starting items: {}
+  Operation: new = {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+                id,
+                items.join(", "),
+                operation,
+                divisor,
+                true_target,
+                false_target
+            ));
+        }
+        notes.join("\n")
+    }
+}
@@ -0,0 +1,289 @@
+use common::{parse_at, Answer, ParseError, Solution, SolveError};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::instrument;
+
+mod generate;
+mod reference;
+
+lazy_static! {
+    static ref MONKEY_RE: Regex = Regex::new(r"Monkey (\d+):\n\s{2}Starting items: (\d+(,\s\d+)*)\n\s{2}Operation: new = (.+)\n\s{2}Test: divisible by (\d+)\n\s{4}If true: throw to monkey (\d+)\n\s{4}If false: throw to monkey (\d+)").unwrap();
+    static ref EXPR_RE: Regex = Regex::new(r"^(old|\-?\d+) (\+|\-|\*|/) (old|\-?\d+)$").unwrap();
+}
+
+/// Puzzle constants, overridable through `common::config`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Rounds simulated while worry is relieved after each inspection.
+    pub relief_rounds: u64,
+    /// Rounds simulated without relief.
+    pub rounds: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            relief_rounds: 20,
+            rounds: 10000,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Arg {
+    Constant(i64),
+    Old,
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone)]
+pub struct SimpleExpression {
+    arg1: Arg,
+    arg2: Arg,
+    op: Operation,
+}
+
+impl SimpleExpression {
+    pub fn new(input: &str, expr: &str) -> Result<Self, ParseError> {
+        let caps = EXPR_RE.captures(expr).ok_or_else(|| {
+            ParseError::at(input, expr, "expected an expression like \"old * 19\"")
+        })?;
+        let parse_arg = |i| {
+            let arg_raw = caps.get(i).unwrap().as_str();
+            if arg_raw == "old" {
+                Ok(Arg::Old)
+            } else {
+                parse_at(input, arg_raw, "an integer").map(Arg::Constant)
+            }
+        };
+        let arg1 = parse_arg(1)?;
+        let arg2 = parse_arg(3)?;
+        let op_raw = caps.get(2).unwrap().as_str();
+        let op = match op_raw {
+            "+" => Operation::Add,
+            "-" => Operation::Subtract,
+            "*" => Operation::Multiply,
+            "/" => Operation::Divide,
+            _ => return Err(ParseError::at(input, op_raw, "invalid operation")),
+        };
+        if let (Operation::Divide, Arg::Constant(0)) = (&op, &arg2) {
+            return Err(ParseError::at(input, expr, "cannot divide by zero"));
+        }
+        Ok(Self { arg1, arg2, op })
+    }
+
+    pub fn interp(&self, old: i64) -> i64 {
+        let a1 = match self.arg1 {
+            Arg::Constant(v) => v,
+            Arg::Old => old,
+        };
+        let a2 = match self.arg2 {
+            Arg::Constant(v) => v,
+            Arg::Old => old,
+        };
+        match self.op {
+            Operation::Add => a1 + a2,
+            Operation::Subtract => a1 - a2,
+            Operation::Multiply => a1 * a2,
+            Operation::Divide => a1 / a2,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    worry_operation: SimpleExpression,
+    test_divisor: i64,
+    true_target_id: i64,
+    false_target_id: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonkeyGroup {
+    monkey_ids: Vec<i64>,
+    monkeys: HashMap<i64, Monkey>,
+    inspections: HashMap<i64, u64>,
+    is_relief: bool,
+}
+
+impl MonkeyGroup {
+    pub fn parse(s: &str) -> Result<MonkeyGroup, ParseError> {
+        let mut monkey_ids: Vec<i64> = Vec::new();
+        let mut monkeys: HashMap<i64, Monkey> = HashMap::new();
+        let mut targets: Vec<(&str, i64)> = Vec::new();
+        let mut last_end = 0;
+        for caps in MONKEY_RE.captures_iter(s) {
+            let m = caps.get(0).unwrap();
+            check_gap(s, &s[last_end..m.start()])?;
+            last_end = m.end();
+
+            let token = |i| caps.get(i).unwrap().as_str();
+            let monkey_id = parse_at::<i64>(s, token(1), "a monkey id")?;
+            if monkeys.contains_key(&monkey_id) {
+                return Err(ParseError::at(s, token(1), "duplicate monkey id"));
+            }
+            let items = token(2)
+                .split(", ")
+                .map(|v| parse_at::<i64>(s, v, "a worry level"))
+                .collect::<Result<Vec<i64>, ParseError>>()?;
+            let worry_operation = SimpleExpression::new(s, token(4))?;
+            let test_divisor = parse_at::<i64>(s, token(5), "a divisor")?;
+            if test_divisor == 0 {
+                return Err(ParseError::at(s, token(5), "cannot divide by zero"));
+            }
+            let true_target = parse_at::<i64>(s, token(6), "a monkey id")?;
+            let false_target = parse_at::<i64>(s, token(7), "a monkey id")?;
+            targets.push((token(6), true_target));
+            targets.push((token(7), false_target));
+            let monkey = Monkey {
+                items,
+                worry_operation,
+                test_divisor,
+                true_target_id: true_target,
+                false_target_id: false_target,
+            };
+            monkeys.insert(monkey_id, monkey);
+            monkey_ids.push(monkey_id);
+        }
+        check_gap(s, &s[last_end..])?;
+        if monkey_ids.is_empty() {
+            return Err(ParseError::at_end(s, "expected at least one monkey"));
+        }
+        if let Some((token, _)) = targets.iter().find(|(_, id)| !monkeys.contains_key(id)) {
+            return Err(ParseError::at(s, token, "no such monkey"));
+        }
+        Ok(MonkeyGroup {
+            monkey_ids,
+            monkeys,
+            inspections: HashMap::new(),
+            is_relief: false,
+        })
+    }
+
+    pub fn with_relief(&self, is_relief: bool) -> MonkeyGroup {
+        MonkeyGroup {
+            is_relief,
+            ..self.clone()
+        }
+    }
+
+    #[instrument(level = "debug", skip_all)]
+    pub fn simulate_round(&mut self) {
+        let divisor_mod = self
+            .monkeys
+            .values()
+            .map(|v| v.test_divisor)
+            .reduce(|a, b| a * b)
+            .unwrap();
+        for monkey_id in &self.monkey_ids {
+            let monkey = self.monkeys.get_mut(monkey_id).unwrap();
+            let items = monkey.items.clone();
+            match self.inspections.get_mut(monkey_id) {
+                Some(v) => *v += items.len() as u64,
+                None => {
+                    self.inspections.insert(*monkey_id, items.len() as u64);
+                }
+            }
+            monkey.items.clear();
+            let monkey = self.monkeys.get(monkey_id).unwrap().clone();
+            for item in items {
+                let item = if self.is_relief {
+                    item
+                } else {
+                    match monkey.worry_operation.op {
+                        Operation::Multiply => item % divisor_mod,
+                        _ => item,
+                    }
+                };
+                let item = monkey.worry_operation.interp(item);
+                let item = if self.is_relief {
+                    (item as f64 / 3.0).floor() as i64
+                } else {
+                    item
+                };
+                if item % monkey.test_divisor == 0 {
+                    let target_monkey = self.monkeys.get_mut(&monkey.true_target_id).unwrap();
+                    target_monkey.items.push(item);
+                } else {
+                    let target_monkey = self.monkeys.get_mut(&monkey.false_target_id).unwrap();
+                    target_monkey.items.push(item);
+                }
+            }
+        }
+    }
+
+    pub fn find_monkey_business(&self) -> u64 {
+        let mut inspection_values = self
+            .inspections
+            .values()
+            .copied()
+            .collect::<Vec<u64>>()
+            .clone();
+        inspection_values.sort_by(|a, b| b.cmp(a));
+        inspection_values.iter().take(2).product()
+    }
+}
+
+/// Rejects anything but whitespace between monkey descriptions.
+fn check_gap(input: &str, gap: &str) -> Result<(), ParseError> {
+    match gap.find(|c: char| !c.is_whitespace()) {
+        Some(idx) => {
+            let text = gap[idx..].lines().next().unwrap_or_default();
+            Err(ParseError::at(input, text, "expected a monkey description"))
+        }
+        None => Ok(()),
+    }
+}
+
+pub fn parse(input: &str) -> Result<MonkeyGroup, ParseError> {
+    MonkeyGroup::parse(input)
+}
+
+pub fn part1(monkey_group: &MonkeyGroup, config: &Config) -> u64 {
+    let mut monkey_group = monkey_group.with_relief(true);
+    for _ in 0..config.relief_rounds {
+        monkey_group.simulate_round();
+    }
+    monkey_group.find_monkey_business()
+}
+
+pub fn part2(monkey_group: &MonkeyGroup, config: &Config) -> u64 {
+    let mut monkey_group = monkey_group.with_relief(false);
+    for _ in 0..config.rounds {
+        monkey_group.simulate_round();
+    }
+    monkey_group.find_monkey_business()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 11;
+    const NAME: &'static str = "monkey-in-the-middle";
+
+    type Input = MonkeyGroup;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkey_group: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(monkey_group, config).into())
+    }
+
+    fn part2(monkey_group: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(monkey_group, config).into())
+    }
+}
@@ -0,0 +1,126 @@
+//! Follows every item through every throw.
+//!
+//! With relief, worry levels stay small enough to track exactly. Without it,
+//! each item is tracked as its remainder modulo every monkey's divisor
+//! separately, which is all a divisibility test can see.
+
+use crate::{Arg, MonkeyGroup, Operation, Puzzle, SimpleExpression};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+/// Throws every item for `rounds` rounds and multiplies the two busiest
+/// monkeys' inspection counts. `inspect` turns an item into its new worry and
+/// `throws_true` runs the monkey's test on it.
+fn monkey_business<T: Clone>(
+    group: &MonkeyGroup,
+    rounds: u64,
+    mut items: Vec<Vec<T>>,
+    inspect: impl Fn(&SimpleExpression, T) -> T,
+    throws_true: impl Fn(&T, usize) -> bool,
+) -> u64 {
+    let ids = &group.monkey_ids;
+    let position = |id: i64| ids.iter().position(|&other| other == id).unwrap();
+    let mut inspections = vec![0; ids.len()];
+    for _ in 0..rounds {
+        for (i, id) in ids.iter().enumerate() {
+            let monkey = &group.monkeys[id];
+            for item in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+                let item = inspect(&monkey.worry_operation, item);
+                let target = if throws_true(&item, i) {
+                    monkey.true_target_id
+                } else {
+                    monkey.false_target_id
+                };
+                items[position(target)].push(item);
+            }
+        }
+    }
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    inspections.iter().take(2).product()
+}
+
+fn operand(arg: &Arg, old: i64) -> i64 {
+    match arg {
+        Arg::Constant(v) => *v,
+        Arg::Old => old,
+    }
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(group: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let items = group
+            .monkey_ids
+            .iter()
+            .map(|id| group.monkeys[id].items.clone())
+            .collect();
+        let business = monkey_business(
+            group,
+            config.relief_rounds,
+            items,
+            |expr, old| {
+                let (a, b) = (operand(&expr.arg1, old), operand(&expr.arg2, old));
+                let new = match expr.op {
+                    Operation::Add => a + b,
+                    Operation::Subtract => a - b,
+                    Operation::Multiply => a * b,
+                    Operation::Divide => a / b,
+                };
+                new.div_euclid(3)
+            },
+            |&item, i| item % group.monkeys[&group.monkey_ids[i]].test_divisor == 0,
+        );
+        Ok(business.into())
+    }
+
+    fn reference_part2(group: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let is_divide = |id| matches!(group.monkeys[id].worry_operation.op, Operation::Divide);
+        if group.monkey_ids.iter().any(is_divide) {
+            return Err(SolveError::NoSolution(String::from(
+                "division can't be tracked by remainders",
+            )));
+        }
+        let divisors = group
+            .monkey_ids
+            .iter()
+            .map(|id| group.monkeys[id].test_divisor)
+            .collect::<Vec<i64>>();
+        let remainders = |item: i64| {
+            divisors
+                .iter()
+                .map(|d| item.rem_euclid(*d))
+                .collect::<Vec<i64>>()
+        };
+        let items = group
+            .monkey_ids
+            .iter()
+            .map(|id| {
+                group.monkeys[id]
+                    .items
+                    .iter()
+                    .map(|&item| remainders(item))
+                    .collect()
+            })
+            .collect();
+        let business = monkey_business(
+            group,
+            config.rounds,
+            items,
+            |expr, old: Vec<i64>| {
+                old.iter()
+                    .zip(&divisors)
+                    .map(|(&r, &d)| {
+                        let (a, b) = (operand(&expr.arg1, r), operand(&expr.arg2, r));
+                        match expr.op {
+                            Operation::Add => (a + b).rem_euclid(d),
+                            Operation::Subtract => (a - b).rem_euclid(d),
+                            _ => (a * b).rem_euclid(d),
+                        }
+                    })
+                    .collect()
+            },
+            |item, i| item[i] == 0,
+        );
+        Ok(business.into())
+    }
+}
@@ -0,0 +1,12 @@
+use common::reference::differential;
+use monkey_in_the_middle::Puzzle;
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn solver_matches_reference(seed in any::<u64>(), size in 1usize..16) {
+        prop_assert_eq!(differential::<Puzzle>(seed, size).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,6 @@
+use monkey_in_the_middle::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
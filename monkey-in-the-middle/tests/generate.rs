@@ -0,0 +1,19 @@
+use common::generate::generate;
+use common::Solution;
+use monkey_in_the_middle::{self as day, Config, Puzzle};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..30) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let config = Config {
+            relief_rounds: 20,
+            rounds: 500,
+        };
+        day::part1(&input, &config);
+        day::part2(&input, &config);
+    }
+}
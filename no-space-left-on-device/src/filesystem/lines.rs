@@ -1,4 +1,4 @@
-use std::io::{BufRead, BufReader};
+use common::{parse_at, ParseError};
 
 #[derive(Debug)]
 pub enum LineParse {
@@ -8,68 +8,73 @@ pub enum LineParse {
   File { name: String, size: u64 },
 }
 
-fn parse_command(line: &String) -> LineParse {
+fn parse_command(input: &str, line: &str) -> Result<LineParse, ParseError> {
   let args = line.split(" ").collect::<Vec<&str>>();
-  match args[1] {
-    "cd" => {
+  match args.get(1) {
+    Some(&"cd") => {
       if args.len() != 3 {
-        panic!("unable to parse cd");
+        return Err(ParseError::at(input, line, "expected \"$ cd <path>\""));
       }
-      LineParse::CD {
+      Ok(LineParse::CD {
         path: String::from(args[2]),
-      }
-    }
-    "ls" => LineParse::LS,
-    _ => {
-      panic!("unable to parse command")
+      })
     }
+    Some(&"ls") => Ok(LineParse::LS),
+    Some(command) => Err(ParseError::at(input, command, "unknown command")),
+    None => Err(ParseError::at(
+      input,
+      line,
+      "expected a command after \"$\"",
+    )),
   }
 }
 
-fn parse_dir(line: &String) -> LineParse {
+fn parse_dir(input: &str, line: &str) -> Result<LineParse, ParseError> {
   let args = line.split(" ").collect::<Vec<&str>>();
-  if args.len() != 2 {
-    panic!("unable to parse dir");
+  if args[0] != "dir" {
+    return Err(ParseError::at(input, args[0], "encountered unknown symbol"));
   }
-  match args[0] {
-    "dir" => LineParse::Dir {
-      name: String::from(args[1]),
-    },
-    _ => {
-      panic!("encountered unknown symbol")
-    }
+  if args.len() != 2 {
+    return Err(ParseError::at(input, line, "expected \"dir <name>\""));
   }
+  Ok(LineParse::Dir {
+    name: String::from(args[1]),
+  })
 }
 
-fn parse_file(line: &String) -> LineParse {
+fn parse_file(input: &str, line: &str) -> Result<LineParse, ParseError> {
   let args = line.split(" ").collect::<Vec<&str>>();
   if args.len() != 2 {
-    panic!("unable to parse file");
+    return Err(ParseError::at(input, line, "expected \"<size> <name>\""));
   }
-  LineParse::File {
+  Ok(LineParse::File {
     name: String::from(args[1]),
-    size: args[0].parse::<u64>().unwrap(),
-  }
+    size: parse_at(input, args[0], "a file size")?,
+  })
 }
 
-pub fn parse<R: BufRead>(r: BufReader<R>) -> Vec<LineParse> {
-  let mut tokens: Vec<LineParse> = Vec::new();
-  for line in r.lines() {
-    let l = line.unwrap();
-    let lookahead = l.as_str().chars().nth(0);
+/// Tokenizes terminal output, keeping each token's source line for error reporting.
+pub fn parse(input: &str) -> Result<Vec<(&str, LineParse)>, ParseError> {
+  let mut tokens: Vec<(&str, LineParse)> = Vec::new();
+  for l in input.lines() {
+    let lookahead = l.chars().next();
     match lookahead {
       Some(v) => match v {
         '$' => {
-          tokens.push(parse_command(&l));
+          tokens.push((l, parse_command(input, l)?));
         }
         'd' => {
-          tokens.push(parse_dir(&l));
+          tokens.push((l, parse_dir(input, l)?));
         }
         '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' | '0' => {
-          tokens.push(parse_file(&l));
+          tokens.push((l, parse_file(input, l)?));
         }
         _ => {
-          panic!("encountered unknown symbol")
+          return Err(ParseError::at(
+            input,
+            &l[..v.len_utf8()],
+            "encountered unknown symbol",
+          ));
         }
       },
       None => {
@@ -77,5 +82,5 @@ pub fn parse<R: BufRead>(r: BufReader<R>) -> Vec<LineParse> {
       }
     }
   }
-  tokens
+  Ok(tokens)
 }
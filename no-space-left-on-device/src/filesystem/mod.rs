@@ -1,2 +1,2 @@
 pub mod lines;
-pub mod tree;
\ No newline at end of file
+pub mod tree;
@@ -1,4 +1,5 @@
 use super::lines::LineParse;
+use common::ParseError;
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -12,13 +13,13 @@ pub enum Item {
 #[derive(Debug, PartialEq)]
 pub struct Node<T> {
   pub value: Item,
-  children: Vec<Rc<RefCell<Node<T>>>>,
+  pub(crate) children: Vec<Rc<RefCell<Node<T>>>>,
 }
 
 impl Node<Item> {
   pub fn total_size(&self) -> u64 {
     match &self.value {
-      Item::Dir { name } => {
+      Item::Dir { .. } => {
         let mut s = 0;
         for child_node in &self.children {
           let child_node = child_node.borrow();
@@ -26,18 +27,36 @@ impl Node<Item> {
         }
         s
       }
-      Item::File { name, size } => *size,
+      Item::File { size, .. } => *size,
     }
   }
 }
 
-pub fn parse(line_parses: Vec<LineParse>) -> Option<Rc<RefCell<Node<Item>>>> {
+/// Largest total of every file listed. Directory sizes are sums of file sizes,
+/// so none of them can overflow either.
+pub const SIZE_LIMIT: u64 = 1 << 40;
+
+/// Builds the directory tree from tokenized terminal output, rooted at the first `cd /`.
+pub fn parse(
+  input: &str,
+  line_parses: Vec<(&str, LineParse)>,
+) -> Result<Rc<RefCell<Node<Item>>>, ParseError> {
   let mut root_rc_opt: Option<Rc<RefCell<Node<Item>>>> = None;
   let mut line_idx = 0;
   let mut node_history: Vec<Rc<RefCell<Node<Item>>>> = Vec::new();
   let mut is_ls = false;
+  let mut listed_size: u64 = 0;
   while line_idx < line_parses.len() {
-    let line_parse = &line_parses[line_idx];
+    let (line, line_parse) = &line_parses[line_idx];
+    let current_dir = || {
+      node_history.last().cloned().ok_or_else(|| {
+        ParseError::at(
+          input,
+          line,
+          "no current directory; expected \"$ cd /\" first",
+        )
+      })
+    };
     match line_parse {
       LineParse::CD { path } => {
         is_ls = false;
@@ -49,38 +68,46 @@ pub fn parse(line_parses: Vec<LineParse>) -> Option<Rc<RefCell<Node<Item>>>> {
               },
               children: Vec::new(),
             };
-            root_rc_opt = Some(Rc::new(RefCell::new(n)));
+            let root_rc = Rc::new(RefCell::new(n));
+            root_rc_opt = Some(root_rc.clone());
             node_history.clear();
-            node_history.push(root_rc_opt.clone().unwrap());
+            node_history.push(root_rc);
           }
           ".." => {
+            current_dir()?;
+            if node_history.len() == 1 {
+              return Err(ParseError::at(
+                input,
+                line,
+                "cannot cd above the root directory",
+              ));
+            }
             node_history.pop();
           }
           _ => {
-            let curr_node_rc = node_history.last().unwrap().clone();
+            let curr_node_rc = current_dir()?;
             let curr_node = curr_node_rc.borrow();
-            match &curr_node.value {
-              Item::Dir { name } => {
-                let child_node_opt = curr_node.children.iter().find(|&v| {
-                  let node = v.borrow();
-                  let child_name = match &node.value {
-                    Item::Dir { name } => name,
-                    Item::File { name, size } => name,
-                  };
-                  child_name == path
-                });
-                match child_node_opt {
-                  Some(child_node) => {
-                    let child_node = child_node.clone();
-                    node_history.push(child_node);
-                  }
-                  None => {
-                    panic!("cannot cd into unknown directory")
-                  }
+            let child_node_opt = curr_node.children.iter().find(|&v| {
+              let node = v.borrow();
+              let child_name = match &node.value {
+                Item::Dir { name } => name,
+                Item::File { name, .. } => name,
+              };
+              child_name == path
+            });
+            match child_node_opt {
+              Some(child_node) => match &child_node.borrow().value {
+                Item::Dir { .. } => node_history.push(child_node.clone()),
+                Item::File { .. } => {
+                  return Err(ParseError::at(input, line, "cannot cd into non-directory"));
                 }
-              }
-              _ => {
-                panic!("cannot cd into non-directory")
+              },
+              None => {
+                return Err(ParseError::at(
+                  input,
+                  line,
+                  "cannot cd into unknown directory",
+                ));
               }
             }
           }
@@ -92,50 +119,44 @@ pub fn parse(line_parses: Vec<LineParse>) -> Option<Rc<RefCell<Node<Item>>>> {
       LineParse::Dir { name } => {
         let dir_name = name;
         if is_ls {
-          let curr_node_rc = node_history.last().unwrap().clone();
-          let mut curr_node = curr_node_rc.borrow_mut();
-          match &curr_node.value {
-            Item::Dir { name } => {
-              let child_node = Rc::new(RefCell::new(Node {
-                value: Item::Dir {
-                  name: String::from(dir_name),
-                },
-                children: Vec::new(),
-              }));
-              curr_node.children.push(child_node.clone());
-            }
-            _ => {
-              panic!("cannot add child to non-directory");
-            }
-          }
+          let curr_node_rc = current_dir()?;
+          let child_node = Rc::new(RefCell::new(Node {
+            value: Item::Dir {
+              name: String::from(dir_name),
+            },
+            children: Vec::new(),
+          }));
+          curr_node_rc.borrow_mut().children.push(child_node);
         }
       }
       LineParse::File { name, size } => {
         let file_name = name;
         if is_ls {
-          let curr_node_rc = node_history.last().unwrap().clone();
-          let mut curr_node = curr_node_rc.borrow_mut();
-          match &curr_node.value {
-            Item::Dir { name } => {
-              let child_node = Rc::new(RefCell::new(Node {
-                value: Item::File {
-                  name: String::from(file_name),
-                  size: *size,
-                },
-                children: Vec::new(),
-              }));
-              curr_node.children.push(child_node.clone());
-            }
-            _ => {
-              panic!("cannot add child to non-directory");
-            }
-          }
+          let curr_node_rc = current_dir()?;
+          listed_size = listed_size
+            .checked_add(*size)
+            .filter(|&total| total <= SIZE_LIMIT)
+            .ok_or_else(|| ParseError::at(input, line, "total file size out of range"))?;
+          let child_node = Rc::new(RefCell::new(Node {
+            value: Item::File {
+              name: String::from(file_name),
+              size: *size,
+            },
+            children: Vec::new(),
+          }));
+          curr_node_rc.borrow_mut().children.push(child_node);
         }
       }
     }
     line_idx += 1;
   }
-  root_rc_opt
+  root_rc_opt.ok_or_else(|| {
+    ParseError::at(
+      input,
+      &input[..0],
+      "terminal output never enters the root directory",
+    )
+  })
 }
 
 pub fn flatten<T>(root_rc: Rc<RefCell<Node<T>>>) -> Vec<Rc<RefCell<Node<T>>>>
@@ -144,7 +165,7 @@ where
 {
   let mut nodes: Vec<Rc<RefCell<Node<T>>>> = Vec::new();
   let mut buffer: Vec<Rc<RefCell<Node<T>>>> = vec![root_rc];
-  while buffer.len() > 0 {
+  while !buffer.is_empty() {
     let mut frontier: Vec<Rc<RefCell<Node<T>>>> = Vec::new();
     for node in &buffer {
       nodes.push(node.clone());
@@ -167,14 +188,14 @@ pub fn find_directories_lte_threshold(
     .filter(|&node_rc| {
       let node = node_rc.borrow();
       match &node.value {
-        Item::Dir { name } => {
+        Item::Dir { .. } => {
           let total_size = node.total_size();
           total_size <= threshold
         }
-        Item::File { name, size } => false,
+        Item::File { .. } => false,
       }
     })
-    .map(|node_rc| node_rc.clone())
+    .cloned()
     .collect::<Vec<Rc<RefCell<Node<Item>>>>>()
 }
 
@@ -188,11 +209,11 @@ pub fn find_smallest_removable_directory(
     .filter(|&node_rc| {
       let node = node_rc.borrow();
       match &node.value {
-        Item::Dir { name } => true,
-        Item::File { name, size } => false,
+        Item::Dir { .. } => true,
+        Item::File { .. } => false,
       }
     })
-    .map(|node| node.clone())
+    .cloned()
     .collect();
   let used_space = root.borrow().total_size();
   node_rcs.sort_by(|a, b| {
@@ -200,11 +221,8 @@ pub fn find_smallest_removable_directory(
     let b_size = b.borrow().total_size();
     a_size.cmp(&b_size)
   });
-  let available_space = total_space - used_space;
-  for node_rc in node_rcs {
-    if node_rc.borrow().total_size() + available_space >= target_space {
-      return Some(node_rc);
-    }
-  }
-  None
+  let available_space = total_space.checked_sub(used_space)?;
+  node_rcs
+    .into_iter()
+    .find(|node_rc| node_rc.borrow().total_size() + available_space >= target_space)
 }
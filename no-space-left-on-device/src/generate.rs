@@ -0,0 +1,72 @@
+//! Random terminal transcripts exploring a tree of `size` files and folders.
+//!
+//! File sizes shrink as the tree grows so the disk never overflows.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+#[derive(Default)]
+struct Dir {
+    dirs: Vec<(String, usize)>,
+    files: Vec<(String, u64)>,
+}
+
+impl Dir {
+    fn has(&self, name: &str) -> bool {
+        self.dirs.iter().any(|(n, _)| n == name) || self.files.iter().any(|(n, _)| n == name)
+    }
+}
+
+fn name(rng: &mut Rng) -> String {
+    let mut name = (0..rng.range(1..=8))
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect::<String>();
+    if rng.chance(0.3) {
+        let extension = *rng.pick(&[".txt", ".dat", ".lst", ".log"]);
+        name.push_str(extension);
+    }
+    name
+}
+
+fn explore(dirs: &[Dir], idx: usize, transcript: &mut String) {
+    let dir = &dirs[idx];
+    transcript.push_str("$ ls\n");
+    for (name, _) in &dir.dirs {
+        transcript.push_str(&format!("dir {}\n", name));
+    }
+    for (name, size) in &dir.files {
+        transcript.push_str(&format!("{} {}\n", size, name));
+    }
+    for (name, child) in &dir.dirs {
+        transcript.push_str(&format!("$ cd {}\n", name));
+        explore(dirs, *child, transcript);
+        transcript.push_str("$ cd ..\n");
+    }
+}
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let max_file_size = (60_000_000 / size.max(1) as i64).clamp(1, 300_000);
+        let mut dirs = vec![Dir::default()];
+        for _ in 0..size {
+            let parent = rng.below(dirs.len());
+            let name = loop {
+                let name = name(rng);
+                if !dirs[parent].has(&name) {
+                    break name;
+                }
+            };
+            if rng.chance(0.3) {
+                dirs.push(Dir::default());
+                let child = dirs.len() - 1;
+                dirs[parent].dirs.push((name, child));
+            } else {
+                let size = rng.range(1..=max_file_size) as u64;
+                dirs[parent].files.push((name, size));
+            }
+        }
+        let mut transcript = String::from("$ cd /\n");
+        explore(&dirs, 0, &mut transcript);
+        transcript
+    }
+}
@@ -0,0 +1,76 @@
+use common::{Answer, ParseError, Solution, SolveError};
+use filesystem::tree::{Item, Node};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub mod filesystem;
+mod generate;
+mod reference;
+
+/// Puzzle constants, overridable through `common::config`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The largest directory size part 1 sums.
+    pub threshold: u64,
+    /// The capacity of the disk.
+    pub total_space: u64,
+    /// The free space the update needs.
+    pub target_space: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            threshold: 100000,
+            total_space: 70000000,
+            target_space: 30000000,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Rc<RefCell<Node<Item>>>, ParseError> {
+    let line_parses = filesystem::lines::parse(input)?;
+    filesystem::tree::parse(input, line_parses)
+}
+
+pub fn part1(root_ref: &Rc<RefCell<Node<Item>>>, config: &Config) -> u64 {
+    filesystem::tree::find_directories_lte_threshold(root_ref.clone(), config.threshold)
+        .iter()
+        .map(|node| node.borrow().total_size())
+        .sum::<u64>()
+}
+
+pub fn part2(root_ref: &Rc<RefCell<Node<Item>>>, config: &Config) -> Option<u64> {
+    filesystem::tree::find_smallest_removable_directory(
+        root_ref.clone(),
+        config.total_space,
+        config.target_space,
+    )
+    .map(|node| node.borrow().total_size())
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    const NAME: &'static str = "no-space-left-on-device";
+
+    type Input = Rc<RefCell<Node<Item>>>;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(root_ref: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(root_ref, config).into())
+    }
+
+    fn part2(root_ref: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        part2(root_ref, config)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("no directory frees enough space")))
+    }
+}
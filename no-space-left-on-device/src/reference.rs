@@ -0,0 +1,61 @@
+//! Re-adds every file below a directory from scratch for each directory.
+
+use crate::filesystem::tree::{Item, Node};
+use crate::Puzzle;
+use common::reference::Reference;
+use common::{Answer, SolveError};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The summed size of every file anywhere below `node`.
+fn size(node: &Node<Item>) -> u64 {
+    match node.value {
+        Item::File { size, .. } => size,
+        Item::Dir { .. } => node
+            .children
+            .iter()
+            .map(|child| size(&child.borrow()))
+            .sum(),
+    }
+}
+
+/// The sizes of `node` and every directory below it.
+fn directory_sizes(node: &Node<Item>, sizes: &mut Vec<u64>) {
+    if let Item::Dir { .. } = node.value {
+        sizes.push(size(node));
+        for child in &node.children {
+            directory_sizes(&child.borrow(), sizes);
+        }
+    }
+}
+
+fn all_directory_sizes(root: &Rc<RefCell<Node<Item>>>) -> Vec<u64> {
+    let mut sizes = Vec::new();
+    directory_sizes(&root.borrow(), &mut sizes);
+    sizes
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(root: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let total: u64 = all_directory_sizes(root)
+            .into_iter()
+            .filter(|&size| size <= config.threshold)
+            .sum();
+        Ok(total.into())
+    }
+
+    fn reference_part2(root: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let used = size(&root.borrow());
+        if used > config.total_space {
+            return Err(SolveError::NoSolution(String::from(
+                "disk is over capacity",
+            )));
+        }
+        all_directory_sizes(root)
+            .into_iter()
+            .filter(|&size| used - size + config.target_space <= config.total_space)
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("no directory frees enough")))
+    }
+}
@@ -0,0 +1,32 @@
+use common::Solution;
+use no_space_left_on_device::filesystem::tree::SIZE_LIMIT;
+use no_space_left_on_device::Puzzle;
+
+#[test]
+fn rejects_file_sizes_that_overflow() {
+    let input = format!(
+        "$ cd /\n$ ls\n{} a\ndir b\n$ cd b\n$ ls\n{} c\n",
+        u64::MAX,
+        u64::MAX
+    );
+    let e = Puzzle::parse(&input).unwrap_err();
+    assert_eq!(
+        (e.line, e.message.as_str()),
+        (3, "total file size out of range")
+    );
+
+    let input = format!("$ cd /\n$ ls\n{} a\n1 b\n", SIZE_LIMIT);
+    let e = Puzzle::parse(&input).unwrap_err();
+    assert_eq!(e.text, "1 b");
+}
+
+#[test]
+fn solves_at_the_size_limit() {
+    let input = format!(
+        "$ cd /\n$ ls\n{} a\ndir b\n$ cd b\n$ ls\n1 c\n",
+        SIZE_LIMIT - 1
+    );
+    let root = Puzzle::parse(&input).unwrap();
+    assert_eq!(Puzzle::part1(&root, &Default::default()), Ok(1u64.into()));
+    assert!(Puzzle::part2(&root, &Default::default()).is_err());
+}
@@ -0,0 +1,6 @@
+use no_space_left_on_device::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
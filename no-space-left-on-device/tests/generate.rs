@@ -0,0 +1,16 @@
+use common::generate::generate;
+use common::Solution;
+use no_space_left_on_device::{self as day, Config, Puzzle};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..100) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let config = Config::default();
+        day::part1(&input, &config);
+        prop_assert!(day::part2(&input, &config).is_some());
+    }
+}
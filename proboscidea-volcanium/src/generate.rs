@@ -0,0 +1,65 @@
+//! A random cave of `size` valves (at most 676), a third of them working.
+//!
+//! Tunnels form a random tree plus a few shortcuts, so every valve is
+//! reachable from the start valve. At most fifteen valves have any flow, as in the real
+//! puzzle, which keeps the elephant search tractable.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+use std::collections::BTreeSet;
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, config: &Self::Config) -> String {
+        let mut names = (b'A'..=b'Z')
+            .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+            .filter(|name| *name != config.start)
+            .collect::<Vec<String>>();
+        rng.shuffle(&mut names);
+        names.insert(0, config.start.clone());
+        names.truncate(size.clamp(2, names.len()));
+
+        let n = names.len();
+        let mut tunnels = vec![BTreeSet::new(); n];
+        let mut connect = |a: usize, b: usize| {
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        };
+        for i in 1..n {
+            connect(i, rng.below(i));
+        }
+        for _ in 0..n / 4 {
+            connect(rng.below(n), rng.below(n));
+        }
+
+        let mut working = (1..n).collect::<Vec<usize>>();
+        rng.shuffle(&mut working);
+        working.truncate((n / 3).clamp(1, 15));
+        let mut rates = vec![0; n];
+        for valve in working {
+            rates[valve] = rng.range(1..=25);
+        }
+
+        (0..n)
+            .map(|i| {
+                let targets = tunnels[i]
+                    .iter()
+                    .map(|&j| names[j].as_str())
+                    .collect::<Vec<&str>>();
+                let leads = if targets.len() == 1 {
+                    "tunnel leads to valve"
+                } else {
+                    "tunnels lead to valves"
+                };
+                format!(
+                    "Valve {} has flow rate={}; {} {}\n",
+                    names[i],
+                    rates[i],
+                    leads,
+                    targets.join(", ")
+                )
+            })
+            .collect()
+    }
+}
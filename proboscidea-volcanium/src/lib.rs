@@ -0,0 +1,461 @@
+use common::{parse_at, Answer, ParseError, Progress, Solution, SolveError};
+use lazy_static::lazy_static;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+mod generate;
+mod reference;
+
+lazy_static! {
+    static ref VALVE_RE: Regex =
+        Regex::new(r"^Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (\w+(, \w+)*)$")
+            .unwrap();
+}
+
+/// Puzzle constants, overridable through `common::config`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Minutes before the eruption when working alone.
+    pub minutes: u64,
+    /// Minutes left after teaching the elephant.
+    pub elephant_minutes: u64,
+    /// The valve everyone starts at.
+    pub start: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            minutes: 30,
+            elephant_minutes: 26,
+            start: String::from("AA"),
+        }
+    }
+}
+
+/// Largest flow rate a valve may have. Pressure is rate times minutes, summed
+/// over every valve opened, which leaves plenty of room in a `u64`.
+pub const RATE_LIMIT: u64 = 1 << 32;
+
+#[derive(Debug)]
+pub struct GraphNode<T> {
+    value: T,
+    neighbors: HashSet<String>,
+}
+
+#[derive(Debug)]
+pub struct Graph<T> {
+    nodes: HashMap<String, GraphNode<T>>,
+}
+
+pub fn parse(input: &str) -> Result<Graph<u64>, ParseError> {
+    let mut nodes = HashMap::new();
+    let mut neighbor_tokens = Vec::new();
+    for l in input.lines() {
+        let cap = VALVE_RE.captures(l).ok_or_else(|| {
+            ParseError::at(
+                input,
+                l,
+                "expected \"Valve AA has flow rate=0; tunnels lead to valves DD, II\"",
+            )
+        })?;
+        let key = cap.get(1).unwrap().as_str();
+        if nodes.contains_key(key) {
+            return Err(ParseError::at(input, key, "duplicate valve"));
+        }
+        let rate_token = cap.get(2).unwrap().as_str();
+        let rate = parse_at(input, rate_token, "a flow rate")?;
+        if rate > RATE_LIMIT {
+            return Err(ParseError::at(input, rate_token, "flow rate out of range"));
+        }
+        let neighbor_keys = cap.get(3).unwrap().as_str().split(", ");
+        neighbor_tokens.extend(neighbor_keys.clone());
+        let neighbors = neighbor_keys.map(String::from).collect();
+        let node = GraphNode {
+            value: rate,
+            neighbors,
+        };
+        nodes.insert(String::from(key), node);
+    }
+    if let Some(token) = neighbor_tokens.iter().find(|&&k| !nodes.contains_key(k)) {
+        return Err(ParseError::at(
+            input,
+            token,
+            "tunnel leads to unknown valve",
+        ));
+    }
+    Ok(Graph { nodes })
+}
+
+impl<T> graph::Graph for Graph<T> {
+    type Node = String;
+    type Map<V> = HashMap<String, V>;
+
+    fn edges(&self, key: &String) -> impl Iterator<Item = (String, u64)> {
+        self.nodes[key].neighbors.iter().map(|k| (k.clone(), 1))
+    }
+
+    fn node_map<V>(&self) -> HashMap<String, V> {
+        HashMap::new()
+    }
+}
+
+pub fn find_shortest_paths(graph: &Graph<u64>) -> HashMap<String, HashMap<String, u64>> {
+    graph::all_pairs(graph, graph.nodes.keys().cloned())
+}
+
+/// The closed, working valves reachable from `key` in time to open them, each
+/// with the minutes left once it is open.
+fn next_valves<'a>(
+    key: &String,
+    minutes_left: u64,
+    graph: &'a Graph<u64>,
+    shortest_paths: &HashMap<String, HashMap<String, u64>>,
+    open_valve_nodes: &HashSet<String>,
+) -> Vec<(&'a String, u64)> {
+    let paths = shortest_paths.get(key).unwrap();
+    let mut next = Vec::new();
+    for (next_key, next_node) in graph.nodes.iter() {
+        let cost = match paths.get(next_key) {
+            Some(&cost) => cost,
+            None => continue,
+        };
+        if minutes_left > cost && !open_valve_nodes.contains(next_key) && next_node.value > 0 {
+            next.push((next_key, minutes_left - cost - 1));
+        }
+    }
+    next
+}
+
+/// Opens `next_key` and carries on alone.
+fn open_solo(
+    next_key: &String,
+    next_minutes_left: u64,
+    graph: &Graph<u64>,
+    shortest_paths: &HashMap<String, HashMap<String, u64>>,
+    open_valve_nodes: &HashSet<String>,
+    progress: &Progress,
+) -> u64 {
+    let mut open_valve_nodes = open_valve_nodes.clone();
+    open_valve_nodes.insert(next_key.clone());
+    next_minutes_left * graph.nodes[next_key].value
+        + find_max_pressure_solo(
+            next_key,
+            next_minutes_left,
+            graph,
+            shortest_paths,
+            open_valve_nodes,
+            progress,
+        )
+}
+
+/// Opens `next_key` and carries on with the elephant still waiting at `key_2`.
+#[allow(clippy::too_many_arguments)]
+fn open_duo(
+    next_key: &String,
+    key_2: &String,
+    next_minutes_left: u64,
+    minutes_left_2: u64,
+    graph: &Graph<u64>,
+    shortest_paths: &HashMap<String, HashMap<String, u64>>,
+    open_valve_nodes: &HashSet<String>,
+    progress: &Progress,
+) -> u64 {
+    let mut open_valve_nodes = open_valve_nodes.clone();
+    open_valve_nodes.insert(next_key.clone());
+    next_minutes_left * graph.nodes[next_key].value
+        + find_max_pressure_duo(
+            next_key,
+            key_2,
+            next_minutes_left,
+            minutes_left_2,
+            graph,
+            shortest_paths,
+            open_valve_nodes,
+            progress,
+        )
+}
+
+/// Once `progress` cancels, the rest of the search is skipped. What comes
+/// back is still the pressure of some real plan, just maybe not the best one.
+pub fn find_max_pressure_solo(
+    key: &String,
+    minutes_left: u64,
+    graph: &Graph<u64>,
+    shortest_paths: &HashMap<String, HashMap<String, u64>>,
+    open_valve_nodes: HashSet<String>,
+    progress: &Progress,
+) -> u64 {
+    if minutes_left == 0 {
+        return 0;
+    }
+    if progress.is_cancelled() {
+        return 0;
+    }
+    if open_valve_nodes.len() == graph.nodes.len() {
+        return 0;
+    }
+    next_valves(key, minutes_left, graph, shortest_paths, &open_valve_nodes)
+        .into_iter()
+        .map(|(next_key, next_minutes_left)| {
+            open_solo(
+                next_key,
+                next_minutes_left,
+                graph,
+                shortest_paths,
+                &open_valve_nodes,
+                progress,
+            )
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+// This is slow because I am bad
+#[allow(clippy::too_many_arguments)]
+pub fn find_max_pressure_duo(
+    key_1: &String,
+    key_2: &String,
+    minutes_left_1: u64,
+    minutes_left_2: u64,
+    graph: &Graph<u64>,
+    shortest_paths: &HashMap<String, HashMap<String, u64>>,
+    open_valve_nodes: HashSet<String>,
+    progress: &Progress,
+) -> u64 {
+    if minutes_left_1 == 0 && minutes_left_2 == 0 {
+        return 0;
+    }
+    if progress.is_cancelled() {
+        return 0;
+    }
+    if open_valve_nodes.len() == graph.nodes.len() {
+        return 0;
+    }
+
+    // Choose to stop and release the elephant
+    let stop = find_max_pressure_solo(
+        key_2,
+        minutes_left_2,
+        graph,
+        shortest_paths,
+        open_valve_nodes.clone(),
+        progress,
+    );
+
+    // Choose to keep going
+    next_valves(
+        key_1,
+        minutes_left_1,
+        graph,
+        shortest_paths,
+        &open_valve_nodes,
+    )
+    .into_iter()
+    .map(|(next_key, next_minutes_left)| {
+        open_duo(
+            next_key,
+            key_2,
+            next_minutes_left,
+            minutes_left_2,
+            graph,
+            shortest_paths,
+            &open_valve_nodes,
+            progress,
+        )
+    })
+    .fold(stop, u64::max)
+}
+
+/// Returns `None` when the start valve doesn't exist.
+///
+/// With the `parallel` feature each first valve is searched on its own thread.
+pub fn part1(cave: &Graph<u64>, config: &Config) -> Option<u64> {
+    part1_with(cave, config, &Progress::new())
+        .map(|searched| searched.expect("a bare Progress never cancels"))
+}
+
+/// Like [`part1`], reporting each first valve searched to `progress`. When
+/// cancelled, the error carries the best pressure found so far.
+pub fn part1_with(
+    cave: &Graph<u64>,
+    config: &Config,
+    progress: &Progress,
+) -> Option<Result<u64, SolveError>> {
+    if !cave.nodes.contains_key(&config.start) {
+        return None;
+    }
+    let shortest_paths = find_shortest_paths(cave);
+    let none_open = HashSet::new();
+    let first = next_valves(
+        &config.start,
+        config.minutes,
+        cave,
+        &shortest_paths,
+        &none_open,
+    );
+    let searched = Searched::new(first.len(), progress);
+    let open_first = |&(next_key, next_minutes_left): &(&String, u64)| {
+        let pressure = open_solo(
+            next_key,
+            next_minutes_left,
+            cave,
+            &shortest_paths,
+            &none_open,
+            progress,
+        );
+        searched.one_more();
+        pressure
+    };
+    #[cfg(feature = "parallel")]
+    let best = first.par_iter().map(open_first).max();
+    #[cfg(not(feature = "parallel"))]
+    let best = first.iter().map(open_first).max();
+    Some(searched.finish(best.unwrap_or(0)))
+}
+
+/// Returns `None` when the start valve doesn't exist.
+///
+/// With the `parallel` feature each first valve is searched on its own thread.
+pub fn part2(cave: &Graph<u64>, config: &Config) -> Option<u64> {
+    part2_with(cave, config, &Progress::new())
+        .map(|searched| searched.expect("a bare Progress never cancels"))
+}
+
+/// Like [`part2`], reporting each first valve searched to `progress`. When
+/// cancelled, the error carries the best pressure found so far.
+pub fn part2_with(
+    cave: &Graph<u64>,
+    config: &Config,
+    progress: &Progress,
+) -> Option<Result<u64, SolveError>> {
+    if !cave.nodes.contains_key(&config.start) {
+        return None;
+    }
+    let shortest_paths = find_shortest_paths(cave);
+    let none_open = HashSet::new();
+    let first = next_valves(
+        &config.start,
+        config.elephant_minutes,
+        cave,
+        &shortest_paths,
+        &none_open,
+    );
+    let searched = Searched::new(first.len(), progress);
+    let open_first = |&(next_key, next_minutes_left): &(&String, u64)| {
+        let pressure = open_duo(
+            next_key,
+            &config.start,
+            next_minutes_left,
+            config.elephant_minutes,
+            cave,
+            &shortest_paths,
+            &none_open,
+            progress,
+        );
+        searched.one_more();
+        pressure
+    };
+    // Releasing the elephant straight away leaves it to work alone.
+    let alone = find_max_pressure_solo(
+        &config.start,
+        config.elephant_minutes,
+        cave,
+        &shortest_paths,
+        HashSet::new(),
+        progress,
+    );
+    #[cfg(feature = "parallel")]
+    let best = first.par_iter().map(open_first).max();
+    #[cfg(not(feature = "parallel"))]
+    let best = first.iter().map(open_first).max();
+    Some(searched.finish(best.map_or(alone, |best| best.max(alone))))
+}
+
+/// Counts first valves searched for `progress`. Only those finished before
+/// any cancellation count, so the search is complete when all of them do.
+struct Searched<'a> {
+    done: AtomicU64,
+    total: u64,
+    progress: &'a Progress,
+}
+
+impl<'a> Searched<'a> {
+    fn new(total: usize, progress: &'a Progress) -> Self {
+        progress.report(0, total as u64);
+        Searched {
+            done: AtomicU64::new(0),
+            total: total as u64,
+            progress,
+        }
+    }
+
+    fn one_more(&self) {
+        if !self.progress.is_cancelled() {
+            let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+            self.progress.report(done, self.total);
+        }
+    }
+
+    fn finish(self, best: u64) -> Result<u64, SolveError> {
+        let done = self.done.into_inner();
+        if done < self.total {
+            return Err(SolveError::Cancelled(format!(
+                "best so far {} after searching {} of {} first valves",
+                best, done, self.total
+            )));
+        }
+        Ok(best)
+    }
+}
+
+fn no_start_valve(config: &Config) -> SolveError {
+    SolveError::NoSolution(format!("no valve named {:?}", config.start))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 16;
+    const NAME: &'static str = "proboscidea-volcanium";
+
+    type Input = Graph<u64>;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cave: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Self::part1_with(cave, config, &Progress::new())
+    }
+
+    fn part1_with(
+        cave: &Self::Input,
+        config: &Self::Config,
+        progress: &Progress,
+    ) -> Result<Answer, SolveError> {
+        part1_with(cave, config, progress)
+            .ok_or_else(|| no_start_valve(config))?
+            .map(Answer::from)
+    }
+
+    fn part2(cave: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        Self::part2_with(cave, config, &Progress::new())
+    }
+
+    fn part2_with(
+        cave: &Self::Input,
+        config: &Self::Config,
+        progress: &Progress,
+    ) -> Result<Answer, SolveError> {
+        part2_with(cave, config, progress)
+            .ok_or_else(|| no_start_valve(config))?
+            .map(Answer::from)
+    }
+}
@@ -0,0 +1,108 @@
+//! Tries every order of opening the working valves, and for the elephant
+//! every way of splitting them between the two of you.
+
+use crate::{Graph, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+/// Tunnel distances between every pair of valves, by Floyd-Warshall.
+fn distances(cave: &Graph<u64>, names: &[&String]) -> Vec<Vec<Option<u64>>> {
+    let n = names.len();
+    let mut dist = vec![vec![None; n]; n];
+    for (i, name) in names.iter().enumerate() {
+        dist[i][i] = Some(0);
+        for neighbor in &cave.nodes[*name].neighbors {
+            let j = names.iter().position(|other| *other == neighbor).unwrap();
+            dist[i][j] = Some(1);
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                    if dist[i][j].is_none_or(|d| a + b < d) {
+                        dist[i][j] = Some(a + b);
+                    }
+                }
+            }
+        }
+    }
+    dist
+}
+
+/// The most pressure released by opening valves from `valves`, in any order,
+/// starting at `at` with `minutes` left.
+fn best_order(
+    dist: &[Vec<Option<u64>>],
+    rates: &[u64],
+    at: usize,
+    minutes: u64,
+    valves: &[usize],
+) -> u64 {
+    let mut best = 0;
+    for (i, &next) in valves.iter().enumerate() {
+        let cost = match dist[at][next] {
+            Some(d) if d < minutes => d + 1,
+            _ => continue,
+        };
+        let mut rest = valves.to_vec();
+        rest.remove(i);
+        let released =
+            (minutes - cost) * rates[next] + best_order(dist, rates, next, minutes - cost, &rest);
+        best = best.max(released);
+    }
+    best
+}
+
+/// Distances and rates indexed by valve, the working valves and the start.
+type Cave = (Vec<Vec<Option<u64>>>, Vec<u64>, Vec<usize>, usize);
+
+fn prepare(cave: &Graph<u64>, start: &String) -> Result<Cave, SolveError> {
+    let mut names = cave.nodes.keys().collect::<Vec<&String>>();
+    names.sort();
+    let start = names
+        .iter()
+        .position(|name| *name == start)
+        .ok_or_else(|| SolveError::NoSolution(String::from("no start valve")))?;
+    let rates = names
+        .iter()
+        .map(|name| cave.nodes[*name].value)
+        .collect::<Vec<u64>>();
+    let working = (0..names.len()).filter(|&i| rates[i] > 0).collect();
+    Ok((distances(cave, &names), rates, working, start))
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(cave: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let (dist, rates, working, start) = prepare(cave, &config.start)?;
+        Ok(best_order(&dist, &rates, start, config.minutes, &working).into())
+    }
+
+    fn reference_part2(cave: &Self::Input, config: &Self::Config) -> Result<Answer, SolveError> {
+        let (dist, rates, working, start) = prepare(cave, &config.start)?;
+        // Each split is a bit mask over the working valves.
+        if working.len() >= u64::BITS as usize {
+            return Err(SolveError::NoSolution(format!(
+                "the reference splits at most {} working valves, not {}",
+                u64::BITS - 1,
+                working.len()
+            )));
+        }
+        let minutes = config.elephant_minutes;
+        let mut best = 0;
+        for mask in 0..1u64 << working.len() {
+            let (mut mine, mut elephants) = (Vec::new(), Vec::new());
+            for (i, &valve) in working.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    mine.push(valve);
+                } else {
+                    elephants.push(valve);
+                }
+            }
+            let released = best_order(&dist, &rates, start, minutes, &mine)
+                + best_order(&dist, &rates, start, minutes, &elephants);
+            best = best.max(released);
+        }
+        Ok(best.into())
+    }
+}
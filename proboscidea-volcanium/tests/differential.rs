@@ -0,0 +1,35 @@
+use common::reference::{differential, Reference};
+use common::{Solution, SolveError};
+use proboscidea_volcanium::Puzzle;
+use proptest::prelude::*;
+
+#[test]
+fn reference_refuses_too_many_valves_to_split() {
+    let names = (b'B'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+        .take(64)
+        .collect::<Vec<String>>();
+    let mut input = format!(
+        "Valve AA has flow rate=0; tunnels lead to valves {}\n",
+        names.join(", ")
+    );
+    for name in &names {
+        input += &format!("Valve {} has flow rate=1; tunnel leads to valve AA\n", name);
+    }
+    let cave = Puzzle::parse(&input).unwrap();
+    assert_eq!(
+        Puzzle::reference_part2(&cave, &Default::default()),
+        Err(SolveError::NoSolution(String::from(
+            "the reference splits at most 63 working valves, not 64"
+        )))
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn solver_matches_reference(seed in any::<u64>(), size in 2usize..18) {
+        prop_assert_eq!(differential::<Puzzle>(seed, size).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,28 @@
+use common::Solution;
+use proboscidea_volcanium::{Puzzle, RATE_LIMIT};
+
+#[test]
+fn rejects_flow_rates_out_of_range() {
+    let input = format!(
+        "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+         Valve BB has flow rate={}; tunnel leads to valve AA\n",
+        u64::MAX
+    );
+    let e = Puzzle::parse(&input).unwrap_err();
+    assert_eq!((e.line, e.message.as_str()), (2, "flow rate out of range"));
+}
+
+#[test]
+fn solves_at_the_rate_limit() {
+    let input = format!(
+        "Valve AA has flow rate={0}; tunnel leads to valve BB\n\
+         Valve BB has flow rate={0}; tunnel leads to valve AA\n",
+        RATE_LIMIT
+    );
+    let cave = Puzzle::parse(&input).unwrap();
+    assert_eq!(
+        Puzzle::part1(&cave, &Default::default()),
+        Ok((RATE_LIMIT * (29 + 27)).into())
+    );
+    assert!(Puzzle::part2(&cave, &Default::default()).is_ok());
+}
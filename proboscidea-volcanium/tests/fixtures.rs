@@ -0,0 +1,6 @@
+use proboscidea_volcanium::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,21 @@
+use common::generate::generate;
+use common::Solution;
+use proboscidea_volcanium::{self as day, Config, Puzzle};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 2usize..12) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        // With as long as working alone, two can always do at least as well.
+        let config = Config {
+            elephant_minutes: 30,
+            ..Config::default()
+        };
+        let alone = day::part1(&input, &config).unwrap();
+        let together = day::part2(&input, &config).unwrap();
+        prop_assert!(alone <= together);
+    }
+}
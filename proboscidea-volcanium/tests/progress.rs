@@ -0,0 +1,48 @@
+use common::{Progress, Solution, SolveError};
+use proboscidea_volcanium::{self as day, Config, Puzzle};
+use std::sync::{Arc, Mutex};
+
+const EXAMPLE: &str = include_str!("../fixtures/example/input.txt");
+
+#[test]
+fn reports_each_first_valve() {
+    let cave = Puzzle::parse(EXAMPLE).unwrap();
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&updates);
+    let progress = Progress::new().on_update(move |update| {
+        seen.lock().unwrap().push(update.done);
+        true
+    });
+    let pressure = day::part2_with(&cave, &Config::default(), &progress).unwrap();
+    assert_eq!(pressure, Ok(1707));
+    let mut updates = updates.lock().unwrap().clone();
+    updates.sort();
+    assert_eq!(updates, vec![0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn stops_when_cancelled() {
+    let cave = Puzzle::parse(EXAMPLE).unwrap();
+    let progress = Progress::new();
+    progress.cancel();
+    match day::part2_with(&cave, &Config::default(), &progress) {
+        Some(Err(SolveError::Cancelled(partial))) => {
+            assert!(partial.ends_with("after searching 0 of 6 first valves"));
+        }
+        answer => panic!("expected a cancellation, got {:?}", answer),
+    }
+}
+
+#[test]
+fn keeps_the_best_plan_found_before_cancelling() {
+    let cave = Puzzle::parse(EXAMPLE).unwrap();
+    // Stops after the first valve is searched.
+    let progress = Progress::new().on_update(|update| update.done < 1);
+    match Puzzle::part1_with(&cave, &Config::default(), &progress) {
+        Err(SolveError::Cancelled(partial)) => {
+            assert!(partial.starts_with("best so far "), "{}", partial);
+            assert!(partial.ends_with("after searching 1 of 6 first valves"));
+        }
+        answer => panic!("expected a cancellation, got {:?}", answer),
+    }
+}
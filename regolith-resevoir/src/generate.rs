@@ -0,0 +1,29 @@
+//! `size` random rock paths scattered below the sand source, some of them a
+//! single point.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let spread = size as i64 + 10;
+        (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (500 + rng.range(-spread..=spread), rng.range(1..=spread));
+                let mut points = vec![format!("{},{}", x, y)];
+                let mut horizontal = rng.chance(0.5);
+                for _ in 0..rng.range(0..=4) {
+                    let step = *rng.pick(&[-1, 1]) * rng.range(1..=6);
+                    if horizontal {
+                        x += step;
+                    } else {
+                        y = (y + step).max(1);
+                    }
+                    horizontal = !horizontal;
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
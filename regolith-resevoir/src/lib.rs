@@ -0,0 +1,219 @@
+use common::{parse_at, Answer, NoConfig, ParseError, Solution, SolveError};
+use grid::{Grid, Pos};
+use tracing::instrument;
+
+mod generate;
+mod reference;
+mod visualize;
+
+const SAND_SOURCE: (i64, i64) = (500, 0);
+
+#[derive(Debug)]
+pub struct Cave {
+    coord_rect_bounds: (i64, i64, i64, i64),
+    data: Grid<char>,
+}
+
+impl Cave {
+    pub fn parse_rock_formation(input: &str, s: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+        let mut pts: Vec<(i64, i64)> = Vec::new();
+        for pt_s in s.split(" -> ") {
+            let pt = match pt_s.split(",").collect::<Vec<&str>>()[..] {
+                [x, y] => (
+                    parse_at(input, x, "an x coordinate")?,
+                    parse_at(input, y, "a y coordinate")?,
+                ),
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        pt_s,
+                        "expected a point like \"498,4\"",
+                    ))
+                }
+            };
+            if pt.1 < 0 {
+                return Err(ParseError::at(input, pt_s, "rock is above the sand source"));
+            }
+            if let Some(&(prev_x, prev_y)) = pts.last() {
+                if prev_x != pt.0 && prev_y != pt.1 {
+                    return Err(ParseError::at(
+                        input,
+                        pt_s,
+                        "rock paths must be horizontal or vertical",
+                    ));
+                }
+            }
+            pts.push(pt);
+        }
+        Ok(pts)
+    }
+
+    pub fn find_coord_rect_bounds(rocks: &[Vec<(i64, i64)>]) -> (i64, i64, i64, i64) {
+        let mut all_pts = vec![SAND_SOURCE];
+        for pts in rocks {
+            for pt in pts {
+                all_pts.push(*pt);
+            }
+        }
+        all_pts.sort_by(|&pt1, &pt2| {
+            let (r1, _) = pt1;
+            let (r2, _) = pt2;
+            r1.cmp(&r2)
+        });
+        let (min_x, _) = *all_pts.first().unwrap();
+        let (max_x, _) = *all_pts.last().unwrap();
+        all_pts.sort_by(|&pt1, &pt2| {
+            let (_, c1) = pt1;
+            let (_, c2) = pt2;
+            c1.cmp(&c2)
+        });
+        let (_, max_y) = *all_pts.last().unwrap();
+        (min_x, max_x, 0, max_y)
+    }
+
+    pub fn new(rocks: &[Vec<(i64, i64)>], has_floor: bool) -> Self {
+        let coord_rect_bounds = if has_floor {
+            let pre_coord_rect_bounds = Self::find_coord_rect_bounds(rocks);
+            let (min_x, max_x, min_y, max_y) = pre_coord_rect_bounds;
+            let dist = max_y + 2 - min_y;
+            (min_x - dist, max_x + dist, min_y, max_y + 2)
+        } else {
+            Self::find_coord_rect_bounds(rocks)
+        };
+        let (min_x, max_x, min_y, max_y) = coord_rect_bounds;
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut data = Grid::new(height, width, '.');
+        if has_floor {
+            for c in 0..width {
+                data[(height - 1, c)] = '#';
+            }
+        }
+
+        let mut cave = Self {
+            coord_rect_bounds,
+            data,
+        };
+        for pts in rocks {
+            // A path of one point is a single rock.
+            if let Some(&first) = pts.first() {
+                let first = cave.coords2idxs(first);
+                cave.data[first] = '#';
+            }
+            for i in 1..pts.len() {
+                let curr = cave.coords2idxs(pts[i - 1]);
+                let dest = cave.coords2idxs(pts[i]);
+                let (dr, dc) = (dest.0 as i64 - curr.0 as i64, dest.1 as i64 - curr.1 as i64);
+                let path = cave
+                    .data
+                    .ray(curr, (dr.signum(), dc.signum()))
+                    .take(dr.abs().max(dc.abs()) as usize)
+                    .collect::<Vec<Pos>>();
+                cave.data[curr] = '#';
+                for pos in path {
+                    cave.data[pos] = '#';
+                }
+            }
+        }
+        cave
+    }
+
+    pub fn simulate_sand(&mut self) -> u64 {
+        self.simulate_sand_with(|_| {})
+    }
+
+    /// Like `simulate_sand`, calling `on_step` after each grain comes to rest.
+    pub fn simulate_sand_with(&mut self, mut on_step: impl FnMut(&Self)) -> u64 {
+        let mut counter = 0;
+        loop {
+            let res = self.drop_sand();
+            match res {
+                Ok(()) => {
+                    counter += 1;
+                    on_step(self);
+                }
+                Err(()) => {
+                    break;
+                }
+            }
+        }
+        counter
+    }
+
+    #[instrument(level = "trace", skip_all)]
+    fn drop_sand(&mut self) -> Result<(), ()> {
+        let mut curr = self.coords2idxs(SAND_SOURCE);
+        if self.data[curr] != '.' {
+            return Err(());
+        }
+        loop {
+            let offsets = [(1, 0), (1, -1), (1, 1)];
+            let mut is_offset_found = false;
+            for offset in offsets {
+                match self.data.offset(curr, offset) {
+                    Some(next) => {
+                        if self.data[next] == '.' {
+                            curr = next;
+                            is_offset_found = true;
+                            break;
+                        }
+                    }
+                    None => return Err(()),
+                }
+            }
+            if !is_offset_found {
+                break;
+            }
+        }
+        self.data[curr] = '0';
+        Ok(())
+    }
+
+    pub fn coords2idxs(&self, coords: (i64, i64)) -> Pos {
+        let (x, y) = coords;
+        let (min_x, _, min_y, _) = self.coord_rect_bounds;
+        let c = x - min_x;
+        let r = y - min_y;
+        (r as usize, c as usize)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<(i64, i64)>>, ParseError> {
+    input
+        .lines()
+        .map(|l| Cave::parse_rock_formation(input, l))
+        .collect()
+}
+
+pub fn part1(rocks: &[Vec<(i64, i64)>]) -> u64 {
+    let mut cave_without_floor = Cave::new(rocks, false);
+    cave_without_floor.simulate_sand()
+}
+
+pub fn part2(rocks: &[Vec<(i64, i64)>]) -> u64 {
+    let mut cave_with_floor = Cave::new(rocks, true);
+    cave_with_floor.simulate_sand()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 14;
+    const NAME: &'static str = "regolith-resevoir";
+
+    type Input = Vec<Vec<(i64, i64)>>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rocks: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(rocks).into())
+    }
+
+    fn part2(rocks: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(rocks).into())
+    }
+}
@@ -0,0 +1,56 @@
+//! Drops each grain from the source and moves it one square at a time
+//! through a set of blocked squares.
+
+use crate::{Puzzle, SAND_SOURCE};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+use std::collections::HashSet;
+
+fn count_resting_grains(rocks: &[Vec<(i64, i64)>], has_floor: bool) -> u64 {
+    let mut blocked = HashSet::new();
+    for path in rocks {
+        for segment in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    blocked.insert((x, y));
+                }
+            }
+        }
+        if let [point] = path[..] {
+            blocked.insert(point);
+        }
+    }
+    let lowest_rock = blocked.iter().map(|&(_, y)| y).max().unwrap_or(0);
+    let floor = lowest_rock + 2;
+
+    let mut grains = 0;
+    while !blocked.contains(&SAND_SOURCE) {
+        let (mut x, mut y) = SAND_SOURCE;
+        loop {
+            if !has_floor && y > lowest_rock {
+                return grains;
+            }
+            let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                .into_iter()
+                .find(|&(nx, ny)| !(blocked.contains(&(nx, ny)) || (has_floor && ny == floor)));
+            match next {
+                Some((nx, ny)) => (x, y) = (nx, ny),
+                None => break,
+            }
+        }
+        blocked.insert((x, y));
+        grains += 1;
+    }
+    grains
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(rocks: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(count_resting_grains(rocks, false).into())
+    }
+
+    fn reference_part2(rocks: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(count_resting_grains(rocks, true).into())
+    }
+}
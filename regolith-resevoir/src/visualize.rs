@@ -0,0 +1,34 @@
+//! Watch the cave fill one grain at a time.
+
+use crate::{Cave, Puzzle, SAND_SOURCE};
+use common::frames::{Frame, Record, Recording, Visualize};
+use common::NoConfig;
+
+/// Draws rock `#`, resting sand `o` and the source `+` as the puzzle does.
+impl Record for Cave {
+    fn frame(&self) -> Frame {
+        let source = self.coords2idxs(SAND_SOURCE);
+        let rows = self
+            .data
+            .positions()
+            .map(|pos| match self.data[pos] {
+                '.' if pos == source => '+',
+                '0' => 'o',
+                c => c,
+            })
+            .collect::<Vec<char>>()
+            .chunks(self.data.width().max(1))
+            .map(|row| row.iter().collect())
+            .collect();
+        Frame { rows }
+    }
+}
+
+impl Visualize for Puzzle {
+    fn record(rocks: &Self::Input, _config: &NoConfig, part: u8, recording: &mut Recording) {
+        let mut cave = Cave::new(rocks, part == 2);
+        recording.step(&cave);
+        cave.simulate_sand_with(|cave| recording.step(cave));
+        recording.finish(&cave);
+    }
+}
@@ -0,0 +1,25 @@
+use common::reference::{compare, differential, Reference};
+use common::Solution;
+use proptest::prelude::*;
+use regolith_resevoir::Puzzle;
+
+#[test]
+fn one_point_paths_are_rock() {
+    let config = Puzzle::reference_config();
+    let point = Puzzle::parse("500,5\n").unwrap();
+    let segment = Puzzle::parse("500,5 -> 500,5\n").unwrap();
+    assert_eq!(compare::<Puzzle>(&point, &config), vec![]);
+    assert_eq!(
+        Puzzle::part2(&point, &config).unwrap(),
+        Puzzle::part2(&segment, &config).unwrap()
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn solver_matches_reference(seed in any::<u64>(), size in 1usize..12) {
+        prop_assert_eq!(differential::<Puzzle>(seed, size).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,6 @@
+use regolith_resevoir::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,14 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use regolith_resevoir::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..15) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        prop_assert!(day::part1(&input) <= day::part2(&input));
+    }
+}
@@ -0,0 +1,20 @@
+use common::frames::{Recording, Visualize};
+use common::Solution;
+use regolith_resevoir::Puzzle;
+use std::fs;
+
+#[test]
+fn records_each_grain() {
+    let input = fs::read_to_string("fixtures/example/input.txt").unwrap();
+    let rocks = Puzzle::parse(&input).unwrap();
+    for (part, grains) in [(1, 24), (2, 93)] {
+        let mut recording = Recording::new(1000);
+        Puzzle::record(&rocks, &Default::default(), part, &mut recording);
+        let frames = recording.frames();
+        assert_eq!(frames.len(), grains + 1);
+        let sand = |i: usize| frames[i].to_string().matches('o').count();
+        assert_eq!(sand(0), 0);
+        assert_eq!(sand(frames.len() - 1), grains);
+        assert!(frames[0].rows[0].contains('+'));
+    }
+}
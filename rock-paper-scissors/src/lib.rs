@@ -0,0 +1,204 @@
+use common::{Answer, NoConfig, ParseError, Solution, SolveError};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+mod generate;
+mod reference;
+
+lazy_static! {
+    static ref ROUND_RE: Regex = Regex::new(r"^(A|B|C)\s(X|Y|Z)$").unwrap();
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Round {
+    pub shape1: Shape,
+    pub shape2: Shape,
+}
+
+pub fn convert_opponent_code_to_shape(code: &str) -> Option<Shape> {
+    if code == "A" {
+        Some(Shape::Rock)
+    } else if code == "B" {
+        Some(Shape::Paper)
+    } else if code == "C" {
+        Some(Shape::Scissors)
+    } else {
+        None
+    }
+}
+
+pub fn convert_player_code_to_shape(code: &str) -> Option<Shape> {
+    if code == "X" {
+        Some(Shape::Rock)
+    } else if code == "Y" {
+        Some(Shape::Paper)
+    } else if code == "Z" {
+        Some(Shape::Scissors)
+    } else {
+        None
+    }
+}
+
+pub fn convert_player_code_to_outcome(code: &str) -> Option<Outcome> {
+    if code == "X" {
+        Some(Outcome::Lose)
+    } else if code == "Y" {
+        Some(Outcome::Draw)
+    } else if code == "Z" {
+        Some(Outcome::Win)
+    } else {
+        None
+    }
+}
+
+pub fn find_player_outcome(round: &Round) -> Outcome {
+    match round.shape1 {
+        Shape::Rock => match round.shape2 {
+            Shape::Rock => Outcome::Draw,
+            Shape::Paper => Outcome::Win,
+            Shape::Scissors => Outcome::Lose,
+        },
+        Shape::Paper => match round.shape2 {
+            Shape::Rock => Outcome::Lose,
+            Shape::Paper => Outcome::Draw,
+            Shape::Scissors => Outcome::Win,
+        },
+        Shape::Scissors => match round.shape2 {
+            Shape::Rock => Outcome::Win,
+            Shape::Paper => Outcome::Lose,
+            Shape::Scissors => Outcome::Draw,
+        },
+    }
+}
+
+pub fn find_player_shape(opponent_shape: Shape, outcome: Outcome) -> Shape {
+    match opponent_shape {
+        Shape::Rock => match outcome {
+            Outcome::Lose => Shape::Scissors,
+            Outcome::Draw => Shape::Rock,
+            Outcome::Win => Shape::Paper,
+        },
+        Shape::Paper => match outcome {
+            Outcome::Lose => Shape::Rock,
+            Outcome::Draw => Shape::Paper,
+            Outcome::Win => Shape::Scissors,
+        },
+        Shape::Scissors => match outcome {
+            Outcome::Lose => Shape::Paper,
+            Outcome::Draw => Shape::Scissors,
+            Outcome::Win => Shape::Rock,
+        },
+    }
+}
+
+pub fn find_player_score(rounds: &[Round]) -> i64 {
+    rounds
+        .iter()
+        .map(|round| {
+            let score1 = match round.shape2 {
+                Shape::Rock => 1,
+                Shape::Paper => 2,
+                Shape::Scissors => 3,
+            };
+            let outcome = find_player_outcome(round);
+            let score2 = match outcome {
+                Outcome::Win => 6,
+                Outcome::Draw => 3,
+                Outcome::Lose => 0,
+            };
+            score1 + score2
+        })
+        .sum()
+}
+
+fn parse_codes<'a>(input: &'a str, l: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    let caps = ROUND_RE
+        .captures(l)
+        .ok_or_else(|| ParseError::at(input, l, "expected a round like \"A X\""))?;
+    Ok((caps.get(1).unwrap().as_str(), caps.get(2).unwrap().as_str()))
+}
+
+fn invalid_code(input: &str, code: &str) -> ParseError {
+    ParseError::at(input, code, "unknown code")
+}
+
+pub fn parse_round_by_shape(input: &str, l: &str) -> Result<Round, ParseError> {
+    let (code1, code2) = parse_codes(input, l)?;
+    let shape1 = convert_opponent_code_to_shape(code1).ok_or_else(|| invalid_code(input, code1))?;
+    let shape2 = convert_player_code_to_shape(code2).ok_or_else(|| invalid_code(input, code2))?;
+    Ok(Round { shape1, shape2 })
+}
+
+pub fn parse_round_by_outcome(input: &str, l: &str) -> Result<Round, ParseError> {
+    let (code1, code2) = parse_codes(input, l)?;
+    let shape1 = convert_opponent_code_to_shape(code1).ok_or_else(|| invalid_code(input, code1))?;
+    let outcome =
+        convert_player_code_to_outcome(code2).ok_or_else(|| invalid_code(input, code2))?;
+    let shape2 = find_player_shape(shape1, outcome);
+    Ok(Round { shape1, shape2 })
+}
+
+#[derive(Debug, Clone)]
+pub struct StrategyGuide {
+    pub rounds_by_shape: Vec<Round>,
+    pub rounds_by_outcome: Vec<Round>,
+}
+
+pub fn parse(input: &str) -> Result<StrategyGuide, ParseError> {
+    let mut rounds_by_shape = vec![];
+    let mut rounds_by_outcome = vec![];
+    for l in input.lines() {
+        rounds_by_shape.push(parse_round_by_shape(input, l)?);
+        rounds_by_outcome.push(parse_round_by_outcome(input, l)?);
+    }
+    Ok(StrategyGuide {
+        rounds_by_shape,
+        rounds_by_outcome,
+    })
+}
+
+pub fn part1(guide: &StrategyGuide) -> i64 {
+    find_player_score(&guide.rounds_by_shape)
+}
+
+pub fn part2(guide: &StrategyGuide) -> i64 {
+    find_player_score(&guide.rounds_by_outcome)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+    const NAME: &'static str = "rock-paper-scissors";
+
+    type Input = StrategyGuide;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(guide: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(guide).into())
+    }
+
+    fn part2(guide: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(guide).into())
+    }
+}
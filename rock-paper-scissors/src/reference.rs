@@ -0,0 +1,49 @@
+//! Scores rounds with modular arithmetic instead of lookup tables.
+//!
+//! Both parts start from `rounds_by_shape`, whose second shape is just the
+//! raw X, Y or Z column, so part 2 doesn't trust the parser's choice of shape.
+
+use crate::{Puzzle, Shape};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+/// Rock, paper and scissors as 0, 1 and 2; each beats the one before it.
+fn index(shape: Shape) -> i64 {
+    match shape {
+        Shape::Rock => 0,
+        Shape::Paper => 1,
+        Shape::Scissors => 2,
+    }
+}
+
+/// The score for playing `player` against `opponent`.
+fn score(opponent: i64, player: i64) -> i64 {
+    let outcome = (player - opponent + 1).rem_euclid(3);
+    player + 1 + 3 * outcome
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(guide: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let total: i64 = guide
+            .rounds_by_shape
+            .iter()
+            .map(|round| score(index(round.shape1), index(round.shape2)))
+            .sum();
+        Ok(total.into())
+    }
+
+    fn reference_part2(guide: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let total: i64 = guide
+            .rounds_by_shape
+            .iter()
+            .map(|round| {
+                // X, Y and Z ask to lose, draw and win: play the shape two,
+                // zero or one steps after the opponent's.
+                let opponent = index(round.shape1);
+                let player = (opponent + index(round.shape2) + 2) % 3;
+                score(opponent, player)
+            })
+            .sum();
+        Ok(total.into())
+    }
+}
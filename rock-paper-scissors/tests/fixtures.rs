@@ -0,0 +1,6 @@
+use rock_paper_scissors::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,16 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use rock_paper_scissors::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..100) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        for score in [day::part1(&input), day::part2(&input)] {
+            prop_assert!((size as i64..=9 * size as i64).contains(&score));
+        }
+    }
+}
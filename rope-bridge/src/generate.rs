@@ -0,0 +1,18 @@
+//! Random head motions: `size` moves of one to ten steps.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&['U', 'D', 'L', 'R']),
+                    rng.range(1..=10)
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,179 @@
+use common::{parse_at, Answer, NoConfig, ParseError, Solution, SolveError};
+use std::collections::HashMap;
+use std::fmt;
+
+mod generate;
+mod reference;
+mod visualize;
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Command {
+    direction: Direction,
+    count: u64,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut cmds: Vec<Command> = Vec::new();
+    for l in input.lines() {
+        let args = l.split(" ").collect::<Vec<&str>>();
+        let direction = match args[0] {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(ParseError::at(input, args[0], "invalid direction")),
+        };
+        let count = match args[1..] {
+            [count] => parse_at(input, count, "a step count")?,
+            _ => return Err(ParseError::at(input, l, "expected a move like \"R 4\"")),
+        };
+        cmds.push(Command { direction, count })
+    }
+    Ok(cmds)
+}
+
+pub struct Simulation {
+    knots: Vec<(i64, i64)>,
+    rect_bounds: (i64, i64, i64, i64),
+    tail_locations: HashMap<(i64, i64), u64>,
+}
+
+impl Simulation {
+    pub fn new(n: usize) -> Simulation {
+        Simulation::with_bounds(n, (-10, -10, 10, 10))
+    }
+
+    /// A rope of `n` knots drawn within `(min_r, min_c, max_r, max_c)`,
+    /// inclusive.
+    pub fn with_bounds(n: usize, rect_bounds: (i64, i64, i64, i64)) -> Simulation {
+        Simulation {
+            knots: (0..n).map(|_| (0, 0)).collect(),
+            rect_bounds,
+            tail_locations: HashMap::new(),
+        }
+    }
+
+    pub fn knots(&self) -> &[(i64, i64)] {
+        &self.knots
+    }
+
+    pub fn execute_command(&mut self, cmd: &Command) {
+        self.execute_command_with(cmd, |_| {});
+    }
+
+    /// Like `execute_command`, calling `on_step` after each single move.
+    pub fn execute_command_with(&mut self, cmd: &Command, mut on_step: impl FnMut(&Self)) {
+        let (dr, dc) = match cmd.direction {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        };
+        for _ in 0..cmd.count {
+            // Move head
+            let (curr_knot_r, curr_knot_c) = self.knots[0];
+            self.knots[0] = (curr_knot_r + dr, curr_knot_c + dc);
+            // Move each knot
+            for i in 1..self.knots.len() {
+                // Move knot if outside of box
+                let (prev_knot_r, prev_knot_c) = self.knots[i - 1];
+                let (curr_knot_r, curr_knot_c) = self.knots[i];
+                if i64::pow(prev_knot_r - curr_knot_r, 2) + i64::pow(prev_knot_c - curr_knot_c, 2)
+                    > 2
+                {
+                    let new_curr_knot_r = if prev_knot_r > curr_knot_r {
+                        curr_knot_r + 1
+                    } else if prev_knot_r < curr_knot_r {
+                        curr_knot_r - 1
+                    } else {
+                        curr_knot_r
+                    };
+                    let new_curr_knot_c = if prev_knot_c > curr_knot_c {
+                        curr_knot_c + 1
+                    } else if prev_knot_c < curr_knot_c {
+                        curr_knot_c - 1
+                    } else {
+                        curr_knot_c
+                    };
+                    self.knots[i] = (new_curr_knot_r, new_curr_knot_c);
+                }
+            }
+
+            let tail = self.knots[self.knots.len() - 1];
+            match self.tail_locations.get_mut(&tail) {
+                Some(v) => *v += 1,
+                None => {
+                    self.tail_locations.insert(tail, 1);
+                }
+            }
+            on_step(self);
+        }
+    }
+}
+
+/// Draws knots over the start `s` and the cells the tail has visited `#`.
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (min_r, min_c, max_r, max_c) = self.rect_bounds;
+        let mut s = String::new();
+        for r in min_r..=max_r {
+            for c in min_c..=max_c {
+                match self.knots.iter().position(|&knot| knot == (r, c)) {
+                    Some(0) => s.push('H'),
+                    Some(i) => s.push_str(&i.to_string()),
+                    None if (r, c) == (0, 0) => s.push('s'),
+                    None if self.tail_locations.contains_key(&(r, c)) => s.push('#'),
+                    None => s.push('.'),
+                }
+            }
+            s.push('\n');
+        }
+        write!(f, "{}", s)
+    }
+}
+
+pub fn part1(cmds: &[Command]) -> usize {
+    let mut two_knot_sim = Simulation::new(2);
+    for cmd in cmds {
+        two_knot_sim.execute_command(cmd);
+    }
+    two_knot_sim.tail_locations.len()
+}
+
+pub fn part2(cmds: &[Command]) -> usize {
+    let mut ten_knot_sim = Simulation::new(10);
+    for cmd in cmds {
+        ten_knot_sim.execute_command(cmd);
+    }
+    ten_knot_sim.tail_locations.len()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 9;
+    const NAME: &'static str = "rope-bridge";
+
+    type Input = Vec<Command>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cmds: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(cmds).into())
+    }
+
+    fn part2(cmds: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(cmds).into())
+    }
+}
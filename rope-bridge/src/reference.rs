@@ -0,0 +1,44 @@
+//! Drags each knot one square at a time and records every square the tail
+//! touches.
+
+use crate::{Command, Direction, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+use std::collections::HashSet;
+
+fn count_tail_squares(cmds: &[Command], n_knots: usize) -> usize {
+    let mut knots = vec![(0i64, 0i64); n_knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for cmd in cmds {
+        let (dx, dy) = match cmd.direction {
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        for _ in 0..cmd.count {
+            knots[0].0 += dx;
+            knots[0].1 += dy;
+            for i in 1..n_knots {
+                let (lead_x, lead_y) = knots[i - 1];
+                let (x, y) = knots[i];
+                // A knot stays put while it still touches the one ahead.
+                if (lead_x - x).abs() > 1 || (lead_y - y).abs() > 1 {
+                    knots[i] = (x + (lead_x - x).signum(), y + (lead_y - y).signum());
+                }
+            }
+            visited.insert(knots[n_knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(cmds: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(count_tail_squares(cmds, 2).into())
+    }
+
+    fn reference_part2(cmds: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(count_tail_squares(cmds, 10).into())
+    }
+}
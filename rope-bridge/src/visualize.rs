@@ -0,0 +1,41 @@
+//! Watch the rope move one step at a time.
+
+use crate::{Puzzle, Simulation};
+use common::frames::{Frame, Record, Recording, Visualize};
+use common::NoConfig;
+
+impl Record for Simulation {
+    fn frame(&self) -> Frame {
+        Frame::from_text(&self.to_string())
+    }
+}
+
+impl Visualize for Puzzle {
+    fn record(cmds: &Self::Input, _config: &NoConfig, part: u8, recording: &mut Recording) {
+        let n = if part == 1 { 2 } else { 10 };
+
+        // A dry run finds everywhere the rope goes, so every frame shows the
+        // same area.
+        let mut bounds = (0, 0, 0, 0);
+        let mut sim = Simulation::new(n);
+        for cmd in cmds {
+            sim.execute_command_with(cmd, |sim| {
+                for &(r, c) in sim.knots() {
+                    bounds = (
+                        bounds.0.min(r),
+                        bounds.1.min(c),
+                        bounds.2.max(r),
+                        bounds.3.max(c),
+                    );
+                }
+            });
+        }
+
+        let mut sim = Simulation::with_bounds(n, bounds);
+        recording.step(&sim);
+        for cmd in cmds {
+            sim.execute_command_with(cmd, |sim| recording.step(sim));
+        }
+        recording.finish(&sim);
+    }
+}
@@ -0,0 +1,6 @@
+use rope_bridge::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,17 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use rope_bridge::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..100) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let short = day::part1(&input);
+        let long = day::part2(&input);
+        prop_assert!(long >= 1);
+        prop_assert!(long <= short);
+    }
+}
@@ -0,0 +1,23 @@
+use common::frames::{Recording, Visualize};
+use common::Solution;
+use rope_bridge::Puzzle;
+use std::fs;
+
+#[test]
+fn records_every_move_in_a_fixed_area() {
+    let input = fs::read_to_string("fixtures/example/input.txt").unwrap();
+    let moves: usize = input
+        .lines()
+        .map(|l| l[2..].parse::<usize>().unwrap())
+        .sum();
+    let cmds = Puzzle::parse(&input).unwrap();
+    for part in [1, 2] {
+        let mut recording = Recording::new(1000);
+        Puzzle::record(&cmds, &Default::default(), part, &mut recording);
+        let frames = recording.frames();
+        assert_eq!(frames.len(), moves + 1);
+        let size = |i: usize| (frames[i].width(), frames[i].height());
+        assert!((0..frames.len()).all(|i| size(i) == size(0)));
+        assert!(frames[0].rows.iter().any(|row| row.contains('H')));
+    }
+}
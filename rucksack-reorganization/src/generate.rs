@@ -0,0 +1,48 @@
+//! Random rucksacks for `size` groups of three elves.
+//!
+//! Every rucksack has exactly one item in both compartments and every group
+//! exactly one badge, as the puzzle promises: each elf draws from its own
+//! slice of the alphabet, split between the compartments, and only the shared
+//! item and the group's badge cross over.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn fill(rng: &mut Rng, mut items: Vec<u8>, pool: &[u8], len: usize) -> Vec<u8> {
+    while items.len() < len {
+        items.push(*rng.pick(pool));
+    }
+    rng.shuffle(&mut items);
+    items
+}
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let mut lines = String::new();
+        for _ in 0..size.max(1) {
+            let mut items = ITEMS.to_vec();
+            rng.shuffle(&mut items);
+            let badge = items[0];
+            for pool in items[1..].chunks(17) {
+                let (left_pool, right_pool) = pool.split_at(8);
+                let shared = if rng.chance(0.1) {
+                    badge
+                } else {
+                    *rng.pick(pool)
+                };
+                let mut left = vec![shared];
+                if shared != badge {
+                    left.push(badge);
+                }
+                let half = rng.range(2..=12) as usize;
+                let left = fill(rng, left, left_pool, half);
+                let right = fill(rng, vec![shared], right_pool, half);
+                lines.push_str(&String::from_utf8([left, right].concat()).unwrap());
+                lines.push('\n');
+            }
+        }
+        lines
+    }
+}
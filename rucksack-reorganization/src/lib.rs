@@ -0,0 +1,137 @@
+use common::{Answer, NoConfig, ParseError, Solution, SolveError};
+use std::collections::HashSet;
+
+mod generate;
+mod reference;
+
+#[derive(Clone, Debug)]
+pub struct Rucksack {
+    items: String,
+}
+
+impl Rucksack {
+    pub fn new(items: String) -> Rucksack {
+        Rucksack { items }
+    }
+
+    pub fn find_common_compartment_item(&self) -> Option<char> {
+        let compartment_size = self.items.len() / 2;
+        let mut compartment1 = HashSet::new();
+        for item in self.items[..compartment_size].chars() {
+            compartment1.insert(item);
+        }
+        let mut compartment2 = HashSet::new();
+        for item in self.items[compartment_size..].chars() {
+            compartment2.insert(item);
+        }
+        let mut intersection = compartment1.intersection(&compartment2);
+        intersection.next().copied()
+    }
+}
+
+pub fn convert_item_to_priority(item: char) -> Option<i64> {
+    if item.is_ascii_lowercase() {
+        Some((item as i64) - ('a' as i64) + 1)
+    } else if item.is_ascii_uppercase() {
+        Some((item as i64) - ('A' as i64) + 27)
+    } else {
+        None
+    }
+}
+
+pub fn find_badge(rucksacks: &[Rucksack]) -> Option<char> {
+    match rucksacks {
+        [r1, r2, r3] => {
+            let mut h1 = HashSet::new();
+            for item in r1.items.chars() {
+                h1.insert(item);
+            }
+            let mut h2 = HashSet::new();
+            for item in r2.items.chars() {
+                h2.insert(item);
+            }
+            let mut h3 = HashSet::new();
+            for item in r3.items.chars() {
+                h3.insert(item);
+            }
+            let mut h12 = HashSet::new();
+            for item in h1.intersection(&h2).enumerate() {
+                h12.insert(*item.1);
+            }
+            let mut h123 = h12.intersection(&h3);
+            h123.next().copied()
+        }
+        _ => None,
+    }
+}
+
+pub fn find_total_rucksack_priority(rucksacks: &[Rucksack]) -> Option<i64> {
+    rucksacks
+        .iter()
+        .map(|r| convert_item_to_priority(r.find_common_compartment_item()?))
+        .sum()
+}
+
+pub fn find_total_group_priority(groups: &[Vec<Rucksack>]) -> Option<i64> {
+    groups
+        .iter()
+        .map(|rucksacks| convert_item_to_priority(find_badge(rucksacks)?))
+        .sum()
+}
+
+fn parse_rucksack(input: &str, l: &str) -> Result<Rucksack, ParseError> {
+    if let Some((idx, c)) = l.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        let item = &l[idx..idx + c.len_utf8()];
+        return Err(ParseError::at(input, item, "expected an item letter"));
+    }
+    if !l.len().is_multiple_of(2) {
+        return Err(ParseError::at(
+            input,
+            l,
+            "expected an even number of items to split into compartments",
+        ));
+    }
+    Ok(Rucksack::new(String::from(l)))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    input.lines().map(|l| parse_rucksack(input, l)).collect()
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Option<i64> {
+    find_total_rucksack_priority(rucksacks)
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Option<i64> {
+    let groups = rucksacks
+        .chunks(3)
+        .map(|group| group.to_vec())
+        .collect::<Vec<Vec<Rucksack>>>();
+    find_total_group_priority(&groups)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+    const NAME: &'static str = "rucksack-reorganization";
+
+    type Input = Vec<Rucksack>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rucksacks: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part1(rucksacks).map(Answer::from).ok_or_else(|| {
+            SolveError::NoSolution(String::from("a rucksack has no item in both compartments"))
+        })
+    }
+
+    fn part2(rucksacks: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part2(rucksacks).map(Answer::from).ok_or_else(|| {
+            SolveError::NoSolution(String::from("a group of three has no common badge"))
+        })
+    }
+}
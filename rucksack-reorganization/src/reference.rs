@@ -0,0 +1,49 @@
+//! Searches for shared items letter by letter.
+
+use crate::Puzzle;
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The priority of the first item every one of `groups` contains.
+fn shared_priority(groups: &[&str]) -> Option<i64> {
+    ITEMS
+        .chars()
+        .position(|item| groups.iter().all(|group| group.contains(item)))
+        .map(|idx| idx as i64 + 1)
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(
+        rucksacks: &Self::Input,
+        _config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        let mut total = 0;
+        for rucksack in rucksacks {
+            let (first, second) = rucksack.items.split_at(rucksack.items.len() / 2);
+            total += shared_priority(&[first, second])
+                .ok_or_else(|| SolveError::NoSolution(String::from("no shared item")))?;
+        }
+        Ok(total.into())
+    }
+
+    fn reference_part2(
+        rucksacks: &Self::Input,
+        _config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(SolveError::NoSolution(String::from("incomplete group")));
+        }
+        let mut total = 0;
+        for group in rucksacks.chunks(3) {
+            let items = group
+                .iter()
+                .map(|r| r.items.as_str())
+                .collect::<Vec<&str>>();
+            total += shared_priority(&items)
+                .ok_or_else(|| SolveError::NoSolution(String::from("no badge")))?;
+        }
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,6 @@
+use rucksack_reorganization::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
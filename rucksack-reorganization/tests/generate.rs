@@ -0,0 +1,16 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use rucksack_reorganization::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..20) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        prop_assert_eq!(input.len(), 3 * size);
+        prop_assert!(day::part1(&input).is_some());
+        prop_assert!(day::part2(&input).is_some());
+    }
+}
@@ -0,0 +1,58 @@
+//! Random crate drawings followed by `size` moves.
+//!
+//! Moves are simulated while they're written so none takes more crates than
+//! its stack holds.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let n_stacks = rng.range(2..=9) as usize;
+        let mut stacks = (0..n_stacks)
+            .map(|_| {
+                (0..rng.range(0..=8))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<Vec<char>>>();
+        if stacks.iter().all(Vec::is_empty) {
+            stacks[0].push('A');
+        }
+
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut drawing = String::new();
+        for level in (0..height).rev() {
+            let row = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => String::from("   "),
+                })
+                .collect::<Vec<String>>();
+            drawing.push_str(&row.join(" "));
+            drawing.push('\n');
+        }
+        let numbers = (1..=n_stacks)
+            .map(|i| format!(" {} ", i))
+            .collect::<Vec<String>>();
+        drawing.push_str(&numbers.join(" "));
+        drawing.push_str("\n\n");
+
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.below(n_stacks);
+                if !stacks[from].is_empty() {
+                    break from;
+                }
+            };
+            let to = (from + 1 + rng.below(n_stacks - 1)) % n_stacks;
+            let count = rng.range(1..=stacks[from].len() as i64) as usize;
+            let remaining = stacks[from].len() - count;
+            let moved = stacks[from].split_off(remaining);
+            stacks[to].extend(moved);
+            drawing.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+        }
+        drawing
+    }
+}
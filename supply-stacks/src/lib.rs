@@ -0,0 +1,218 @@
+use common::{parse_at, Answer, NoConfig, ParseError, Solution, SolveError};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+mod generate;
+mod reference;
+
+lazy_static! {
+    static ref INSTRUCTION_RE: Regex =
+        Regex::new(r"^move\s(\d+)\sfrom\s(\d+)\sto\s(\d+)$").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub struct Crates {
+    stacks: Vec<Vec<String>>,
+}
+
+impl Crates {
+    pub fn new(input: &str, lines: &[&str]) -> Result<Crates, ParseError> {
+        let (nums_line, crate_lines) = lines.split_last().ok_or_else(|| {
+            ParseError::at(input, &input[..0], "expected a drawing of the crate stacks")
+        })?;
+        let mut n_crates = 0;
+        for num in nums_line.split_whitespace() {
+            parse_at::<usize>(input, num, "a stack number")?;
+            n_crates += 1;
+        }
+        if n_crates == 0 {
+            return Err(ParseError::at(input, nums_line, "expected stack numbers"));
+        }
+        let mut stacks: Vec<Vec<String>> = Vec::new();
+        for _ in 0..n_crates {
+            stacks.push(Vec::new());
+        }
+        for line in crate_lines.iter().rev() {
+            let line_chars: Vec<(usize, char)> = line.char_indices().collect();
+            let mut p = 0;
+            let mut counter = 0;
+            while p < line_chars.len() {
+                let (idx, c) = line_chars[p];
+                let token = &line[idx..idx + c.len_utf8()];
+                if c == '[' {
+                    let label = match line_chars.get(p + 1..p + 3) {
+                        Some(&[(_, label), (_, ']')]) if label.is_alphanumeric() => label,
+                        _ => {
+                            return Err(ParseError::at(
+                                input,
+                                &line[idx..],
+                                "expected a crate like \"[A]\"",
+                            ))
+                        }
+                    };
+                    if counter >= n_crates {
+                        return Err(ParseError::at(
+                            input,
+                            token,
+                            "crate is outside of the numbered stacks",
+                        ));
+                    }
+                    stacks[counter].push(String::from(label));
+                } else if c != ' ' {
+                    return Err(ParseError::at(input, token, "expected a crate or a space"));
+                }
+                p += 4;
+                counter += 1;
+            }
+        }
+        Ok(Crates { stacks })
+    }
+
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    pub fn top(&self) -> Vec<String> {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last().cloned())
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct CrateMover9000 {}
+
+impl CrateMover9000 {
+    pub fn new() -> CrateMover9000 {
+        CrateMover9000 {}
+    }
+
+    pub fn execute(&self, crates: &mut Crates, ins: &Instruction) -> Option<()> {
+        for _ in 0..ins.quantity {
+            let v = crates.stacks[ins.from].pop()?;
+            crates.stacks[ins.to].push(v);
+        }
+        Some(())
+    }
+}
+
+#[derive(Default)]
+pub struct CrateMover9001 {}
+
+impl CrateMover9001 {
+    pub fn new() -> CrateMover9001 {
+        CrateMover9001 {}
+    }
+
+    pub fn execute(&self, crates: &mut Crates, ins: &Instruction) -> Option<()> {
+        let mut buffer = Vec::new();
+        for _ in 0..ins.quantity {
+            let v = crates.stacks[ins.from].pop()?;
+            buffer.push(v);
+        }
+        while let Some(v) = buffer.pop() {
+            crates.stacks[ins.to].push(v);
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    from: usize,
+    to: usize,
+    quantity: u64,
+}
+
+impl Instruction {
+    pub fn new(input: &str, line: &str, n_stacks: usize) -> Result<Instruction, ParseError> {
+        let cap = INSTRUCTION_RE.captures(line).ok_or_else(|| {
+            ParseError::at(
+                input,
+                line,
+                "expected an instruction like \"move 1 from 2 to 3\"",
+            )
+        })?;
+        let stack = |i| {
+            let token = cap.get(i).unwrap().as_str();
+            match parse_at::<usize>(input, token, "a stack number")? {
+                n @ 1.. if n <= n_stacks => Ok(n - 1),
+                _ => Err(ParseError::at(input, token, "no such stack")),
+            }
+        };
+        Ok(Instruction {
+            from: stack(2)?,
+            to: stack(3)?,
+            quantity: parse_at(input, cap.get(1).unwrap().as_str(), "a crate count")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    pub crates: Crates,
+    pub instructions: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Result<Procedure, ParseError> {
+    let mut lines = input.lines();
+    let crate_lines: Vec<&str> = lines.by_ref().take_while(|l| !l.is_empty()).collect();
+    let crates = Crates::new(input, &crate_lines)?;
+    let instructions = lines
+        .filter(|l| !l.is_empty())
+        .map(|l| Instruction::new(input, l, crates.len()))
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+    Ok(Procedure {
+        crates,
+        instructions,
+    })
+}
+
+pub fn part1(procedure: &Procedure) -> Option<String> {
+    let mut crate_mover_9000_crates = procedure.crates.clone();
+    let crate_mover_9000 = CrateMover9000::new();
+    for ins in &procedure.instructions {
+        crate_mover_9000.execute(&mut crate_mover_9000_crates, ins)?;
+    }
+    Some(crate_mover_9000_crates.top().join(""))
+}
+
+pub fn part2(procedure: &Procedure) -> Option<String> {
+    let mut crate_mover_9001_crates = procedure.crates.clone();
+    let crate_mover_9001 = CrateMover9001::new();
+    for ins in &procedure.instructions {
+        crate_mover_9001.execute(&mut crate_mover_9001_crates, ins)?;
+    }
+    Some(crate_mover_9001_crates.top().join(""))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    const NAME: &'static str = "supply-stacks";
+
+    type Input = Procedure;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(procedure: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part1(procedure).map(Answer::from).ok_or_else(|| {
+            SolveError::NoSolution(String::from("moves a crate from an empty stack"))
+        })
+    }
+
+    fn part2(procedure: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part2(procedure).map(Answer::from).ok_or_else(|| {
+            SolveError::NoSolution(String::from("moves a crate from an empty stack"))
+        })
+    }
+}
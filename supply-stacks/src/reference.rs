@@ -0,0 +1,42 @@
+//! Replays the moves on plain vectors, one crate or one slice at a time.
+
+use crate::{Procedure, Puzzle};
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+fn run(procedure: &Procedure, keep_order: bool) -> Result<Answer, SolveError> {
+    let mut stacks = procedure.crates.stacks.clone();
+    for ins in &procedure.instructions {
+        let from = &mut stacks[ins.from];
+        let quantity = ins.quantity as usize;
+        if quantity > from.len() {
+            return Err(SolveError::NoSolution(String::from("stack runs out")));
+        }
+        let mut moved = from.split_off(from.len() - quantity);
+        if !keep_order {
+            moved.reverse();
+        }
+        stacks[ins.to].extend(moved);
+    }
+    let tops = stacks
+        .iter()
+        .filter_map(|stack| stack.last().cloned())
+        .collect::<String>();
+    Ok(tops.into())
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(
+        procedure: &Self::Input,
+        _config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        run(procedure, false)
+    }
+
+    fn reference_part2(
+        procedure: &Self::Input,
+        _config: &Self::Config,
+    ) -> Result<Answer, SolveError> {
+        run(procedure, true)
+    }
+}
@@ -0,0 +1,12 @@
+use common::reference::differential;
+use proptest::prelude::*;
+use supply_stacks::Puzzle;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn solver_matches_reference(seed in any::<u64>(), size in 1usize..100) {
+        prop_assert_eq!(differential::<Puzzle>(seed, size).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,6 @@
+use supply_stacks::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
@@ -0,0 +1,17 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use supply_stacks::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 1usize..100) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        prop_assert_eq!(input.instructions.len(), size);
+        let crate_mover_9000 = day::part1(&input).unwrap();
+        let crate_mover_9001 = day::part2(&input).unwrap();
+        prop_assert_eq!(crate_mover_9000.len(), crate_mover_9001.len());
+    }
+}
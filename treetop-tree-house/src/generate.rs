@@ -0,0 +1,18 @@
+//! A random forest `size` trees on a side.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                let row = (0..side)
+                    .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                    .collect::<String>();
+                row + "\n"
+            })
+            .collect()
+    }
+}
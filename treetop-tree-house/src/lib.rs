@@ -0,0 +1,85 @@
+use common::{Answer, NoConfig, ParseError, Solution, SolveError};
+use grid::ORTHOGONAL;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub use grid::Grid;
+
+mod generate;
+mod reference;
+
+pub fn is_visible(grid: &Grid<u64>, r: usize, c: usize) -> bool {
+    let height = grid[(r, c)];
+    ORTHOGONAL
+        .iter()
+        .any(|&step| grid.ray((r, c), step).all(|pos| grid[pos] < height))
+}
+
+pub fn find_scenic_score(grid: &Grid<u64>, r: usize, c: usize) -> u64 {
+    let height = grid[(r, c)];
+    let mut scenic_score = 1;
+    for &step in &ORTHOGONAL {
+        let mut dist = 0;
+        for pos in grid.ray((r, c), step) {
+            dist += 1;
+            if grid[pos] >= height {
+                break;
+            }
+        }
+        scenic_score *= dist;
+    }
+    scenic_score
+}
+
+pub fn count_visible_trees(grid: &Grid<u64>) -> u64 {
+    grid.positions()
+        .filter(|&(r, c)| is_visible(grid, r, c))
+        .count() as u64
+}
+
+/// Scores rows of trees in parallel with the `parallel` feature.
+pub fn find_max_scenic_score(grid: &Grid<u64>) -> u64 {
+    let score_row = |r| (0..grid.width()).map(move |c| find_scenic_score(grid, r, c));
+    #[cfg(feature = "parallel")]
+    let best = (0..grid.height())
+        .into_par_iter()
+        .flat_map_iter(score_row)
+        .max();
+    #[cfg(not(feature = "parallel"))]
+    let best = (0..grid.height()).flat_map(score_row).max();
+    best.unwrap_or(0)
+}
+
+pub fn parse(input: &str) -> Result<Grid<u64>, ParseError> {
+    Grid::parse(input, "a tree height", |c| c.to_digit(10).map(u64::from))
+}
+
+pub fn part1(grid: &Grid<u64>) -> u64 {
+    count_visible_trees(grid)
+}
+
+pub fn part2(grid: &Grid<u64>) -> u64 {
+    find_max_scenic_score(grid)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+    const NAME: &'static str = "treetop-tree-house";
+
+    type Input = Grid<u64>;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        Ok(part2(grid).into())
+    }
+}
@@ -0,0 +1,47 @@
+//! Walks outwards from every tree with plain index arithmetic.
+
+use crate::Puzzle;
+use common::reference::Reference;
+use common::{Answer, SolveError};
+use grid::Grid;
+
+/// The heights seen looking from `(r, c)` in each direction, nearest first.
+fn sightlines(grid: &Grid<u64>, r: usize, c: usize) -> [Vec<u64>; 4] {
+    let up = (0..r).rev().map(|i| grid[(i, c)]).collect();
+    let down = (r + 1..grid.height()).map(|i| grid[(i, c)]).collect();
+    let left = (0..c).rev().map(|j| grid[(r, j)]).collect();
+    let right = (c + 1..grid.width()).map(|j| grid[(r, j)]).collect();
+    [up, down, left, right]
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(grid: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let mut visible = 0u64;
+        for r in 0..grid.height() {
+            for c in 0..grid.width() {
+                let height = grid[(r, c)];
+                let lines = sightlines(grid, r, c);
+                if lines.iter().any(|line| line.iter().all(|&h| h < height)) {
+                    visible += 1;
+                }
+            }
+        }
+        Ok(visible.into())
+    }
+
+    fn reference_part2(grid: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        let mut best = 0u64;
+        for r in 0..grid.height() {
+            for c in 0..grid.width() {
+                let height = grid[(r, c)];
+                let mut score = 1;
+                for line in sightlines(grid, r, c) {
+                    let blocker = line.iter().position(|&h| h >= height);
+                    score *= blocker.map_or(line.len(), |idx| idx + 1) as u64;
+                }
+                best = best.max(score);
+            }
+        }
+        Ok(best.into())
+    }
+}
@@ -0,0 +1,12 @@
+use common::reference::differential;
+use proptest::prelude::*;
+use treetop_tree_house::Puzzle;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn solver_matches_reference(seed in any::<u64>(), size in 1usize..20) {
+        prop_assert_eq!(differential::<Puzzle>(seed, size).unwrap(), vec![]);
+    }
+}
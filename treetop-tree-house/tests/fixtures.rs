@@ -0,0 +1,6 @@
+use treetop_tree_house::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
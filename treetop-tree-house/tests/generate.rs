@@ -0,0 +1,17 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use treetop_tree_house::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 2usize..30) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let visible = day::part1(&input);
+        prop_assert!(visible >= 4 * (size as u64 - 1));
+        prop_assert!(visible <= (size * size) as u64);
+        day::part2(&input);
+    }
+}
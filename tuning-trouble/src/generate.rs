@@ -0,0 +1,22 @@
+//! A random datastream of about `size` characters.
+//!
+//! The stream draws from a random slice of the alphabet, so markers can be
+//! rare or missing from it, and ends with fourteen distinct letters so both
+//! markers always exist.
+
+use crate::Puzzle;
+use common::generate::{Generate, Rng};
+
+impl Generate for Puzzle {
+    fn generate(rng: &mut Rng, size: usize, _config: &Self::Config) -> String {
+        let letters = rng.range(2..=26) as u8;
+        let mut stream = (0..size)
+            .map(|_| (b'a' + rng.below(letters as usize) as u8) as char)
+            .collect::<String>();
+        let mut tail = (b'a'..=b'z').map(char::from).collect::<Vec<char>>();
+        rng.shuffle(&mut tail);
+        stream.extend(&tail[..14]);
+        stream.push('\n');
+        stream
+    }
+}
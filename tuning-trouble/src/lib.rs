@@ -0,0 +1,71 @@
+use common::{Answer, NoConfig, ParseError, Solution, SolveError};
+use std::collections::HashMap;
+
+mod generate;
+mod reference;
+
+pub fn find_start_index(buffer: &str, unique_char_count: usize) -> Option<usize> {
+    let mut h = HashMap::new();
+    let chars = buffer.chars();
+    for (idx, c) in chars.enumerate() {
+        match h.get_mut(&c) {
+            Some(v) => {
+                *v += 1;
+            }
+            None => {
+                h.insert(c, 1);
+            }
+        }
+        if idx >= unique_char_count {
+            let last_c = buffer.chars().nth(idx - unique_char_count).unwrap();
+            let v = h.get_mut(&last_c).unwrap();
+            if *v == 1 {
+                h.remove(&last_c);
+            } else {
+                *v -= 1;
+            }
+        }
+        if h.len() == unique_char_count && h.iter().fold(true, |acc, (_, v)| acc && *v == 1) {
+            return Some(idx);
+        }
+    }
+    None
+}
+
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    Ok(String::from(input.trim_end()))
+}
+
+pub fn part1(buffer: &str) -> Option<usize> {
+    find_start_index(buffer, 4).map(|idx| idx + 1)
+}
+
+pub fn part2(buffer: &str) -> Option<usize> {
+    find_start_index(buffer, 14).map(|idx| idx + 1)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    const NAME: &'static str = "tuning-trouble";
+
+    type Input = String;
+    type Config = NoConfig;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(buffer: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part1(buffer)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("no start-of-packet marker")))
+    }
+
+    fn part2(buffer: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        part2(buffer)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::NoSolution(String::from("no start-of-message marker")))
+    }
+}
@@ -0,0 +1,27 @@
+//! Compares every pair of characters in every window.
+
+use crate::Puzzle;
+use common::reference::Reference;
+use common::{Answer, SolveError};
+
+fn find_marker(buffer: &str, len: usize) -> Result<Answer, SolveError> {
+    let chars = buffer.chars().collect::<Vec<char>>();
+    for end in len..=chars.len() {
+        let window = &chars[end - len..end];
+        let distinct = (0..len).all(|i| (i + 1..len).all(|j| window[i] != window[j]));
+        if distinct {
+            return Ok(end.into());
+        }
+    }
+    Err(SolveError::NoSolution(String::from("no marker")))
+}
+
+impl Reference for Puzzle {
+    fn reference_part1(buffer: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        find_marker(buffer, 4)
+    }
+
+    fn reference_part2(buffer: &Self::Input, _config: &Self::Config) -> Result<Answer, SolveError> {
+        find_marker(buffer, 14)
+    }
+}
@@ -0,0 +1,6 @@
+use tuning_trouble::Puzzle;
+
+#[test]
+fn fixtures() {
+    common::fixtures::assert_all::<Puzzle>("fixtures");
+}
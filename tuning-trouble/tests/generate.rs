@@ -0,0 +1,16 @@
+use common::generate::generate;
+use common::Solution;
+use proptest::prelude::*;
+use tuning_trouble::{self as day, Puzzle};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn generated_inputs_solve(seed in any::<u64>(), size in 0usize..200) {
+        let input = Puzzle::parse(&generate::<Puzzle>(seed, size, &Default::default())).unwrap();
+        let packet = day::part1(&input).unwrap();
+        let message = day::part2(&input).unwrap();
+        prop_assert!(packet <= message);
+    }
+}
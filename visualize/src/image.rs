@@ -0,0 +1,139 @@
+use crate::{bounds, Options};
+use common::frames::Frame;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// A color per distinct character, in order of first appearance. Blank
+/// padding is always index 0.
+struct Palette {
+    indices: HashMap<char, u8>,
+    rgb: Vec<u8>,
+}
+
+impl Palette {
+    fn new(frames: &[Frame]) -> io::Result<Self> {
+        let mut palette = Palette {
+            indices: HashMap::new(),
+            rgb: Vec::new(),
+        };
+        palette.add(' ')?;
+        for frame in frames {
+            for c in frame.rows.iter().flat_map(|row| row.chars()) {
+                palette.add(c)?;
+            }
+        }
+        Ok(palette)
+    }
+
+    fn add(&mut self, c: char) -> io::Result<()> {
+        if self.indices.contains_key(&c) {
+            return Ok(());
+        }
+        let index = u8::try_from(self.indices.len())
+            .map_err(|_| invalid("frames use more than 256 distinct characters"))?;
+        self.indices.insert(c, index);
+        self.rgb.extend(color(c));
+        Ok(())
+    }
+}
+
+/// Puzzle characters get fixed colors; anything else a stable arbitrary one.
+fn color(c: char) -> [u8; 3] {
+    match c {
+        ' ' | '.' => [16, 16, 32],
+        '#' => [200, 200, 210],
+        'o' => [230, 190, 90],
+        '+' => [255, 255, 255],
+        'H' => [230, 70, 60],
+        's' => [90, 200, 90],
+        '1'..='9' => {
+            let d = c as u8 - b'0';
+            [240, 120 + 12 * d, 60]
+        }
+        _ => {
+            let h = (c as u32).wrapping_mul(2654435761);
+            [
+                64 + (h >> 24) as u8 % 192,
+                64 + (h >> 16) as u8 % 192,
+                64 + (h >> 8) as u8 % 192,
+            ]
+        }
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Image size in pixels, checked against the 16-bit limit GIF imposes.
+fn size(frames: &[Frame], scale: usize) -> io::Result<(u16, u16)> {
+    if frames.is_empty() {
+        return Err(invalid("no frames to write"));
+    }
+    let (width, height) = bounds(frames);
+    let pixels = |cells: usize| {
+        cells
+            .max(1)
+            .checked_mul(scale.max(1))
+            .and_then(|n| u16::try_from(n).ok())
+            .ok_or_else(|| invalid("frames are too large for an image; lower the scale"))
+    };
+    Ok((pixels(width)?, pixels(height)?))
+}
+
+/// Palette indices for `frame`, each character a `scale`-pixel square.
+fn pixels(frame: &Frame, palette: &Palette, size: (u16, u16), scale: usize) -> Vec<u8> {
+    let (width, height) = (size.0 as usize, size.1 as usize);
+    let scale = scale.max(1);
+    let mut pixels = vec![0; width * height];
+    for (r, row) in frame.rows.iter().enumerate() {
+        for (c, ch) in row.chars().enumerate() {
+            let index = palette.indices[&ch];
+            for y in r * scale..(r + 1) * scale {
+                pixels[y * width + c * scale..y * width + (c + 1) * scale].fill(index);
+            }
+        }
+    }
+    pixels
+}
+
+/// Writes an animated GIF that loops forever.
+pub fn write_gif(frames: &[Frame], options: &Options, w: impl Write) -> io::Result<()> {
+    let size = size(frames, options.scale)?;
+    let palette = Palette::new(frames)?;
+    let mut encoder =
+        gif::Encoder::new(w, size.0, size.1, &palette.rgb).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    // GIF delays are in hundredths of a second.
+    let delay = (100 / options.fps.max(1)).max(1) as u16;
+    for frame in frames {
+        let pixels = pixels(frame, &palette, size, options.scale);
+        let mut frame = gif::Frame::from_indexed_pixels(size.0, size.1, pixels, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Writes an animated PNG that loops forever.
+pub fn write_apng(frames: &[Frame], options: &Options, w: impl Write) -> io::Result<()> {
+    let size = size(frames, options.scale)?;
+    let palette = Palette::new(frames)?;
+    let mut encoder = png::Encoder::new(w, size.0 as u32, size.1 as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette.rgb.clone());
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(io::Error::other)?;
+    let fps = u16::try_from(options.fps.max(1)).unwrap_or(u16::MAX);
+    encoder.set_frame_delay(1, fps).map_err(io::Error::other)?;
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    for frame in frames {
+        let pixels = pixels(frame, &palette, size, options.scale);
+        writer.write_image_data(&pixels).map_err(io::Error::other)?;
+    }
+    writer.finish().map_err(io::Error::other)
+}
@@ -0,0 +1,32 @@
+//! Exporters for frames recorded from the simulation puzzles: plain text dumps,
+//! playback in a terminal, and animated GIF or APNG files.
+
+use common::frames::Frame;
+
+mod image;
+mod text;
+
+pub use image::{write_apng, write_gif};
+pub use text::{play, write_text};
+
+/// How animations are paced and sized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub fps: u32,
+    /// Pixels per character in images.
+    pub scale: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { fps: 10, scale: 4 }
+    }
+}
+
+/// The smallest size in characters that holds every frame. Frames that grow
+/// are drawn from the top-left, padded with blanks.
+fn bounds(frames: &[Frame]) -> (usize, usize) {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    (width, height)
+}
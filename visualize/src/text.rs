@@ -0,0 +1,29 @@
+use crate::Options;
+use common::frames::Frame;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Writes every frame under a `Frame i/n` header, separated by blank lines.
+pub fn write_text(frames: &[Frame], mut w: impl Write) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        writeln!(w, "Frame {}/{}", i + 1, frames.len())?;
+        writeln!(w, "{}", frame)?;
+    }
+    Ok(())
+}
+
+/// Plays the frames in place on an ANSI terminal at `options.fps`.
+pub fn play(frames: &[Frame], options: &Options, mut w: impl Write) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / options.fps.max(1) as f64);
+    // Clear once and then redraw over the top, which flickers less than
+    // clearing before every frame.
+    write!(w, "\x1b[2J\x1b[?25l")?;
+    for (i, frame) in frames.iter().enumerate() {
+        write!(w, "\x1b[H{}Frame {}/{}\x1b[J", frame, i + 1, frames.len())?;
+        w.flush()?;
+        thread::sleep(delay);
+    }
+    writeln!(w, "\x1b[?25h")?;
+    w.flush()
+}
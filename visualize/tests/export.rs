@@ -0,0 +1,60 @@
+use common::frames::Frame;
+use visualize::{write_apng, write_gif, write_text, Options};
+
+fn frames() -> Vec<Frame> {
+    vec![
+        Frame::from_text("..\n.."),
+        Frame::from_text("#.\n.."),
+        Frame::from_text("#o.\n..\n+"),
+    ]
+}
+
+const OPTIONS: Options = Options { fps: 10, scale: 2 };
+
+#[test]
+fn dumps_text() {
+    let mut out = Vec::new();
+    write_text(&frames()[..2], &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Frame 1/2\n..\n..\n\nFrame 2/2\n#.\n..\n\n"
+    );
+}
+
+#[test]
+fn writes_gif_frames_at_the_largest_size() {
+    let mut out = Vec::new();
+    write_gif(&frames(), &OPTIONS, &mut out).unwrap();
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::Indexed);
+    let mut decoder = options.read_info(out.as_slice()).unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (6, 6));
+    let mut count = 0;
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(frame.delay, 10);
+        count += 1;
+    }
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn writes_apng_frames() {
+    let mut out = Vec::new();
+    write_apng(&frames(), &OPTIONS, &mut out).unwrap();
+    let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+    let info = reader.info();
+    assert_eq!((info.width, info.height), (6, 6));
+    assert_eq!(info.animation_control.unwrap().num_frames, 3);
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let mut count = 0;
+    while reader.next_frame(&mut buf).is_ok() {
+        count += 1;
+    }
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn rejects_empty_recordings() {
+    assert!(write_gif(&[], &OPTIONS, Vec::new()).is_err());
+    assert!(write_apng(&[], &OPTIONS, Vec::new()).is_err());
+}